@@ -0,0 +1,59 @@
+use std::path::PathBuf;
+
+use aoc_common::{
+    bench, solve, solve_timed, Answer, BenchConfig, Benchmark, Part, Result, TimedAnswer,
+};
+
+pub struct Day {
+    pub number: u8,
+    pub solve: fn(&str, Part) -> Result<Answer>,
+    pub solve_timed: fn(&str, Part) -> Result<TimedAnswer>,
+    pub bench: fn(&str, &BenchConfig) -> Result<Benchmark>,
+}
+
+impl Day {
+    pub fn find(number: u8) -> Option<&'static Day> {
+        DAYS.iter().find(|day| day.number == number)
+    }
+
+    pub fn dir(&self) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(format!("q_{}", self.number))
+    }
+}
+
+macro_rules! day {
+    ($number:literal, $solution:ty) => {
+        Day {
+            number: $number,
+            solve: solve::<$solution>,
+            solve_timed: solve_timed::<$solution>,
+            bench: bench::<$solution>,
+        }
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!(1, q_1::Day1),
+    day!(2, q_2::Day2),
+    day!(3, q_3::Day3),
+    day!(4, q_4::Day4),
+    day!(5, q_5::Day5),
+    day!(6, q_6::Day6),
+    day!(7, q_7::Day7),
+    day!(8, q_8::Day8),
+    day!(9, q_9::Day9),
+    day!(10, q_10::Day10),
+    day!(11, q_11::Day11),
+    day!(12, q_12::Day12),
+    day!(13, q_13::Day13),
+    day!(14, q_14::Day14),
+    day!(15, q_15::Day15),
+    day!(18, q_18::Day18),
+    day!(19, q_19::Day19),
+    day!(21, q_21::Day21),
+    day!(22, q_22::Day22),
+    day!(23, q_23::Day23),
+    day!(25, q_25::Day25),
+];
@@ -0,0 +1,236 @@
+use std::{path::PathBuf, process};
+
+use aoc_common::{Answer, BenchConfig, BenchReport, InputSource, Part, TimedAnswer};
+use clap::{Parser, Subcommand, ValueEnum};
+use days::{Day, DAYS};
+use output::{input_hash, PartResult};
+
+mod days;
+mod output;
+mod verify;
+
+#[derive(Parser)]
+#[command(about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solver for a single day, or for every day
+    Run {
+        day: Option<u8>,
+
+        /// Only run this part (both parts are run by default)
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Path to the puzzle input, or `-` for stdin (defaults to the day's `data/input.txt`)
+        #[arg(short, long, requires = "day")]
+        input: Option<PathBuf>,
+
+        /// Use the day's bundled `data/demo.txt`
+        #[arg(long, conflicts_with = "input")]
+        demo: bool,
+
+        #[arg(short, long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+    /// Time parsing and both parts of a day, or of every day
+    Bench {
+        day: Option<u8>,
+
+        /// Number of untimed runs before measuring
+        #[arg(long, default_value_t = BenchConfig::default().warmup)]
+        warmup: usize,
+
+        /// Number of timed runs
+        #[arg(short = 'n', long, default_value_t = BenchConfig::default().iterations)]
+        iterations: usize,
+
+        /// Use every day's bundled `data/demo.txt` instead of `data/input.txt`
+        #[arg(long)]
+        demo: bool,
+
+        #[arg(short, long, value_enum, default_value_t = ReportFormat::Table)]
+        format: ReportFormat,
+    },
+    /// Check answers against each day's `data/answers.toml`
+    Verify { day: Option<u8> },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum OutputFormat {
+    Text,
+    /// One object per part with the answer, timings and a hash of the input
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ReportFormat {
+    Table,
+    Csv,
+}
+
+fn run(
+    days: &[&Day],
+    parts: &[Part],
+    source: InputSource,
+    format: OutputFormat,
+) -> Result<(), String> {
+    let mut results = Vec::new();
+
+    for day in days {
+        let input = match source.read(&day.dir()) {
+            Ok(input) => input,
+            Err(err) if days.len() > 1 => {
+                eprintln!("Skipping day {}: {}", day.number, err);
+                continue;
+            }
+            Err(err) => return Err(err),
+        };
+        let input_hash = input_hash(&input);
+
+        for part in parts {
+            let timed = (day.solve_timed)(&input, *part).map_err(|err| {
+                format!("Couldn't parse the input of day {}: {}", day.number, err)
+            })?;
+
+            match format {
+                OutputFormat::Text => print_answer(day.number, *part, &timed),
+                OutputFormat::Json => {
+                    results.push(PartResult::new(day.number, *part, timed, &input_hash))
+                }
+            }
+        }
+    }
+
+    if let OutputFormat::Json = format {
+        println!("{}", serde_json::to_string_pretty(&results).unwrap());
+    }
+
+    Ok(())
+}
+
+fn print_answer(day: u8, part: Part, timed: &TimedAnswer) {
+    match &timed.answer {
+        Answer::Unsolved => println!("Day {}, part {}: not implemented", day, part),
+        Answer::NoSolution => println!(
+            "Day {}, part {}: no solution ({:.2?})",
+            day,
+            part,
+            timed.total()
+        ),
+        answer => {
+            let answer = answer.to_string();
+            if answer.contains('\n') {
+                println!("Day {}, part {} ({:.2?}):", day, part, timed.total());
+                println!("{}", answer.trim_end());
+            } else {
+                println!(
+                    "Day {}, part {}: {} ({:.2?})",
+                    day,
+                    part,
+                    answer,
+                    timed.total()
+                )
+            }
+        }
+    }
+}
+
+fn bench(
+    days: &[&Day],
+    config: &BenchConfig,
+    source: InputSource,
+    format: ReportFormat,
+) -> Result<(), String> {
+    let mut report = BenchReport::default();
+
+    for day in days {
+        let input = match source.read(&day.dir()) {
+            Ok(input) => input,
+            // not every day has a demo or a real input checked in
+            Err(err) if days.len() > 1 => {
+                eprintln!("Skipping day {}: {}", day.number, err);
+                continue;
+            }
+            Err(err) => return Err(err),
+        };
+
+        let benchmark = (day.bench)(&input, config)
+            .map_err(|err| format!("Couldn't parse the input of day {}: {}", day.number, err))?;
+        report.add(day.number, benchmark.timings);
+    }
+
+    match format {
+        ReportFormat::Table => print!("{}", report.to_table()),
+        ReportFormat::Csv => print!("{}", report.to_csv()),
+    }
+
+    Ok(())
+}
+
+fn find_day(number: u8) -> Result<&'static Day, String> {
+    Day::find(number).ok_or_else(|| format!("There is no solution for day {}", number))
+}
+
+/// The given day, or every day when none is given.
+fn find_days(number: Option<u8>) -> Result<Vec<&'static Day>, String> {
+    match number {
+        Some(number) => find_day(number).map(|day| vec![day]),
+        None => Ok(DAYS.iter().collect()),
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    let res = match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            demo,
+            format,
+        } => {
+            let days = find_days(day);
+            let parts = match part.and_then(Part::from_number) {
+                Some(part) => vec![part],
+                None => Part::ALL.to_vec(),
+            };
+            let source = match input {
+                Some(path) => InputSource::from_path(path),
+                None if demo => InputSource::Demo,
+                None => InputSource::Default,
+            };
+            days.and_then(|days| run(&days, &parts, source, format))
+        }
+        Command::Bench {
+            day,
+            warmup,
+            iterations,
+            demo,
+            format,
+        } => {
+            let days = find_days(day);
+            let config = BenchConfig { warmup, iterations };
+            let source = if demo {
+                InputSource::Demo
+            } else {
+                InputSource::Default
+            };
+            days.and_then(|days| bench(&days, &config, source, format))
+        }
+        Command::Verify { day } => {
+            let days = find_days(day);
+            days.and_then(|days| verify::verify(&days))
+        }
+    };
+
+    if let Err(err) = res {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,94 @@
+use aoc_common::{Answer, Part, TimedAnswer};
+use serde::Serialize;
+
+/// The outcome of running one part of a day, in the shape `aoc run --format json` prints it.
+#[derive(Debug, Serialize)]
+pub struct PartResult {
+    pub day: u8,
+    pub part: u8,
+    /// A number or some text, `null` when the part isn't solved
+    pub answer: Option<JsonAnswer>,
+    pub timings: JsonTimings,
+    /// Tells apart results computed on different inputs
+    pub input_hash: String,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum JsonAnswer {
+    Number(i128),
+    Text(String),
+}
+
+#[derive(Debug, Serialize)]
+pub struct JsonTimings {
+    pub parse_ns: u128,
+    pub solve_ns: u128,
+    pub total_ns: u128,
+}
+
+impl PartResult {
+    pub fn new(day: u8, part: Part, timed: TimedAnswer, input_hash: &str) -> Self {
+        let timings = JsonTimings {
+            parse_ns: timed.parse.as_nanos(),
+            solve_ns: timed.solve.as_nanos(),
+            total_ns: timed.total().as_nanos(),
+        };
+        let answer = match timed.answer {
+            Answer::Number(number) => Some(JsonAnswer::Number(number)),
+            Answer::Text(text) => Some(JsonAnswer::Text(text)),
+            Answer::Unsolved | Answer::NoSolution => None,
+        };
+
+        Self {
+            day,
+            part: part.number(),
+            answer,
+            timings,
+            input_hash: input_hash.to_owned(),
+        }
+    }
+}
+
+/// 64-bit FNV-1a of the input, prefixed with the algorithm so it can be changed later.
+///
+/// Unlike `DefaultHasher` this is stable across Rust releases, so hashes can be compared between
+/// runs on different machines.
+pub fn input_hash(input: &str) -> String {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    let hash = input.bytes().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(PRIME)
+    });
+
+    format!("fnv1a64:{:016x}", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn input_hash_works() {
+        assert_eq!(input_hash(""), "fnv1a64:cbf29ce484222325");
+        assert_eq!(input_hash("a"), "fnv1a64:af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn part_result_json_works() {
+        let timed = TimedAnswer {
+            answer: Answer::Unsolved,
+            parse: Duration::from_nanos(10),
+            solve: Duration::from_nanos(5),
+        };
+        let res = PartResult::new(12, Part::Two, timed, "fnv1a64:0");
+
+        assert_eq!(
+            serde_json::to_string(&res).unwrap(),
+            r#"{"day":12,"part":2,"answer":null,"timings":{"parse_ns":10,"solve_ns":5,"total_ns":15},"input_hash":"fnv1a64:0"}"#
+        );
+    }
+}
@@ -0,0 +1,264 @@
+use std::{
+    fmt::{self, Write},
+    fs::read_to_string,
+    io,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+};
+
+use aoc_common::{InputSource, Part};
+use serde::Deserialize;
+
+use crate::days::Day;
+
+/// The answers a day is expected to give, read from its `data/answers.toml`.
+///
+/// ```toml
+/// [demo]
+/// part1 = 24000
+/// part2 = "MCD"
+///
+/// [input]
+/// part1 = '''
+/// ##..
+/// '''
+/// ```
+///
+/// Parts without an expected answer aren't run at all, which keeps unsolved and very slow parts
+/// out of the way.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Answers {
+    demo: Option<Expected>,
+    input: Option<Expected>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Expected {
+    part1: Option<ExpectedAnswer>,
+    part2: Option<ExpectedAnswer>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ExpectedAnswer {
+    Number(i64),
+    Text(String),
+}
+
+impl Answers {
+    /// Read `data/answers.toml` in `day_dir`, or nothing if the day doesn't have one.
+    pub fn load(day_dir: &Path) -> Result<Option<Self>, String> {
+        let path = day_dir.join("data").join("answers.toml");
+        let text = match read_to_string(&path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(format!("Couldn't read {}: {}", path.display(), err)),
+        };
+
+        toml::from_str(&text)
+            .map(Some)
+            .map_err(|err| format!("Couldn't parse {}: {}", path.display(), err))
+    }
+
+    fn sections(&self) -> [(&'static str, InputSource, Option<&Expected>); 2] {
+        [
+            ("demo", InputSource::Demo, self.demo.as_ref()),
+            ("input", InputSource::Default, self.input.as_ref()),
+        ]
+    }
+}
+
+impl Expected {
+    fn get(&self, part: Part) -> Option<String> {
+        let answer = match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        };
+
+        answer.map(|answer| match answer {
+            ExpectedAnswer::Number(number) => number.to_string(),
+            ExpectedAnswer::Text(text) => text.clone(),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Outcome {
+    Pass,
+    Mismatch {
+        expected: String,
+        actual: String,
+    },
+    /// The input couldn't be read or parsed, or the solution panicked.
+    Fail(String),
+    /// There's nothing to check against, e.g. the real input isn't checked in.
+    Skipped(String),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Pass => write!(f, "ok"),
+            Outcome::Mismatch { expected, actual } => {
+                write!(f, "MISMATCH\n{}", diff(expected, actual))
+            }
+            Outcome::Fail(err) => write!(f, "FAILED: {}", err),
+            Outcome::Skipped(reason) => write!(f, "skipped ({})", reason),
+        }
+    }
+}
+
+/// Compare `expected` and `actual` line by line, marking the lines that differ.
+fn diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<_> = expected.lines().collect();
+    let actual: Vec<_> = actual.lines().collect();
+    let mut res = String::new();
+
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(expected), Some(actual)) if expected == actual => {
+                writeln!(res, "    {}", expected).unwrap()
+            }
+            (expected, actual) => {
+                if let Some(expected) = expected {
+                    writeln!(res, "  - {}", expected).unwrap();
+                }
+                if let Some(actual) = actual {
+                    writeln!(res, "  + {}", actual).unwrap();
+                }
+            }
+        }
+    }
+
+    res
+}
+
+fn check(day: &Day, input: &str, part: Part, expected: String) -> Outcome {
+    let res = panic::catch_unwind(AssertUnwindSafe(|| (day.solve)(input, part)));
+
+    match res {
+        Ok(Ok(answer)) => {
+            let actual = answer.to_string();
+            if actual.trim_end() == expected.trim_end() {
+                Outcome::Pass
+            } else {
+                Outcome::Mismatch { expected, actual }
+            }
+        }
+        Ok(Err(err)) => Outcome::Fail(format!("couldn't parse the input: {}", err)),
+        Err(_) => Outcome::Fail("the solution panicked".to_owned()),
+    }
+}
+
+#[derive(Debug, Default)]
+struct Summary {
+    passed: usize,
+    mismatched: usize,
+    failed: usize,
+    skipped: usize,
+}
+
+impl Summary {
+    fn add(&mut self, outcome: &Outcome) {
+        match outcome {
+            Outcome::Pass => self.passed += 1,
+            Outcome::Mismatch { .. } => self.mismatched += 1,
+            Outcome::Fail(_) => self.failed += 1,
+            Outcome::Skipped(_) => self.skipped += 1,
+        }
+    }
+}
+
+/// Check every day in `days` against its expected answers, printing one line per part.
+///
+/// Fails if any answer doesn't match or couldn't be computed.
+pub fn verify(days: &[&Day]) -> Result<(), String> {
+    let mut summary = Summary::default();
+
+    for day in days {
+        let dir = day.dir();
+        let answers = match Answers::load(&dir)? {
+            Some(answers) => answers,
+            None => {
+                let outcome = Outcome::Skipped("no data/answers.toml".to_owned());
+                println!("Day {}: {}", day.number, outcome);
+                summary.add(&outcome);
+                continue;
+            }
+        };
+
+        for (name, source, expected) in answers.sections() {
+            let Some(expected) = expected else {
+                continue;
+            };
+
+            let input = source.read(&dir);
+            for part in Part::ALL {
+                let Some(expected) = expected.get(part) else {
+                    continue;
+                };
+
+                let outcome = match &input {
+                    Ok(input) => check(day, input, part, expected),
+                    Err(err) => Outcome::Skipped(err.clone()),
+                };
+                println!("Day {} {}, part {}: {}", day.number, name, part, outcome);
+                summary.add(&outcome);
+            }
+        }
+    }
+
+    println!(
+        "\n{} passed, {} mismatched, {} failed, {} skipped",
+        summary.passed, summary.mismatched, summary.failed, summary.skipped
+    );
+
+    if summary.mismatched + summary.failed > 0 {
+        Err("Some answers don't match".to_owned())
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::DAYS;
+
+    #[test]
+    fn diff_works() {
+        assert_eq!(
+            diff("#.#\n...\n###\n", "#.#\n.#.\n###"),
+            "    #.#\n  - ...\n  + .#.\n    ###\n"
+        );
+        assert_eq!(diff("1\n2", "1"), "    1\n  - 2\n");
+    }
+
+    #[test]
+    fn answers_files_parse() {
+        for day in DAYS {
+            assert!(Answers::load(&day.dir()).is_ok(), "day {}", day.number);
+        }
+    }
+
+    #[test]
+    fn check_works() {
+        let day = Day::find(1).unwrap();
+        let input = "1000\n2000\n\n4000\n\n500";
+
+        assert_eq!(check(day, input, Part::One, "4000".into()), Outcome::Pass);
+        assert_eq!(
+            check(day, input, Part::Two, "7000".into()),
+            Outcome::Mismatch {
+                expected: "7000".into(),
+                actual: "7500".into()
+            }
+        );
+        assert!(matches!(
+            check(day, "x", Part::One, "0".into()),
+            Outcome::Fail(_)
+        ));
+    }
+}
@@ -0,0 +1,252 @@
+use std::{
+    fmt::{self, Write},
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::{Answer, Part, Result, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Runs whose timings are thrown away before measuring
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+impl BenchConfig {
+    /// Time a single run, for solutions too slow to repeat.
+    pub const ONCE: BenchConfig = BenchConfig {
+        warmup: 0,
+        iterations: 1,
+    };
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 1,
+            iterations: 10,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+
+        let len = samples.len();
+        let median = if len.is_multiple_of(2) {
+            (samples[len / 2 - 1] + samples[len / 2]) / 2
+        } else {
+            samples[len / 2]
+        };
+
+        Self {
+            iterations: len,
+            min: samples[0],
+            median,
+            mean: samples.iter().sum::<Duration>() / len as u32,
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {:.2?}, median {:.2?}, mean {:.2?} over {} runs",
+            self.min, self.median, self.mean, self.iterations
+        )
+    }
+}
+
+/// Time `f` according to `config`, returning the result of the last run along with the timings.
+pub fn measure<T, F>(config: &BenchConfig, mut f: F) -> (T, Stats)
+where
+    F: FnMut() -> T,
+{
+    for _ in 0..config.warmup {
+        black_box(f());
+    }
+
+    let mut samples = Vec::with_capacity(config.iterations);
+    let mut res = None;
+    for _ in 0..config.iterations.max(1) {
+        let timer = Instant::now();
+        let value = black_box(f());
+        samples.push(timer.elapsed());
+        res = Some(value);
+    }
+
+    (res.unwrap(), Stats::from_samples(samples))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl Timings {
+    fn steps(&self) -> [(&'static str, &Stats); 3] {
+        [
+            ("parse", &self.parse),
+            ("part1", &self.part1),
+            ("part2", &self.part2),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Benchmark {
+    pub answers: [Answer; 2],
+    pub timings: Timings,
+}
+
+/// Time parsing and both parts of a solution on `input`.
+pub fn bench<S: Solution>(input: &str, config: &BenchConfig) -> Result<Benchmark> {
+    let (parsed, parse) = measure(config, || S::parse(input));
+    let parsed = parsed?;
+    let (answer1, part1) = measure(config, || S::part1(&parsed));
+    let (answer2, part2) = measure(config, || S::part2(&parsed));
+
+    Ok(Benchmark {
+        answers: [answer1, answer2],
+        timings: Timings {
+            parse,
+            part1,
+            part2,
+        },
+    })
+}
+
+/// The answer to a single part, with parsing and solving timed separately.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimedAnswer {
+    pub answer: Answer,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+impl TimedAnswer {
+    pub fn total(&self) -> Duration {
+        self.parse + self.solve
+    }
+}
+
+/// Like [`solve`](crate::solve), but also reports how long each step took.
+pub fn solve_timed<S: Solution>(input: &str, part: Part) -> Result<TimedAnswer> {
+    let timer = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse = timer.elapsed();
+
+    let timer = Instant::now();
+    let answer = match part {
+        Part::One => S::part1(&parsed),
+        Part::Two => S::part2(&parsed),
+    };
+
+    Ok(TimedAnswer {
+        answer,
+        parse,
+        solve: timer.elapsed(),
+    })
+}
+
+/// Timings of several days, rendered as a table or as CSV.
+#[derive(Debug, Default)]
+pub struct BenchReport {
+    days: Vec<(u8, Timings)>,
+}
+
+impl BenchReport {
+    pub fn add(&mut self, day: u8, timings: Timings) {
+        self.days.push((day, timings));
+    }
+
+    pub fn to_table(&self) -> String {
+        let mut res = format!(
+            "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}\n",
+            "day", "step", "min", "median", "mean"
+        );
+
+        for (day, timings) in self.days.iter() {
+            for (step, stats) in timings.steps() {
+                writeln!(
+                    res,
+                    "{:>3}  {:<5}  {:>12}  {:>12}  {:>12}",
+                    day,
+                    step,
+                    format!("{:.2?}", stats.min),
+                    format!("{:.2?}", stats.median),
+                    format!("{:.2?}", stats.mean)
+                )
+                .unwrap();
+            }
+        }
+
+        res
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut res = String::from("day,step,iterations,min_ns,median_ns,mean_ns\n");
+
+        for (day, timings) in self.days.iter() {
+            for (step, stats) in timings.steps() {
+                writeln!(
+                    res,
+                    "{},{},{},{},{},{}",
+                    day,
+                    step,
+                    stats.iterations,
+                    stats.min.as_nanos(),
+                    stats.median.as_nanos(),
+                    stats.mean.as_nanos()
+                )
+                .unwrap();
+            }
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_works() {
+        let samples = [4, 1, 3, 8].map(Duration::from_millis).to_vec();
+        let stats = Stats::from_samples(samples);
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(3500));
+        assert_eq!(stats.mean, Duration::from_millis(4));
+    }
+
+    #[test]
+    fn measure_works() {
+        let mut runs = 0;
+        let config = BenchConfig {
+            warmup: 2,
+            iterations: 5,
+        };
+        let (res, stats) = measure(&config, || {
+            runs += 1;
+            runs
+        });
+
+        assert_eq!(res, 7);
+        assert_eq!(stats.iterations, 5);
+    }
+}
@@ -0,0 +1,153 @@
+use std::{error, fmt};
+
+pub type Result<T> = std::result::Result<T, ParseError>;
+
+/// An error raised while parsing puzzle input.
+///
+/// Line and column numbers start at 1, like in an editor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    message: String,
+    line: Option<usize>,
+    column: Option<usize>,
+    text: Option<String>,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            line: None,
+            column: None,
+            text: None,
+        }
+    }
+
+    /// Record the piece of input that couldn't be parsed.
+    pub fn with_text(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column = Some(column);
+        self
+    }
+
+    /// Point the error at `token`, which has to be a slice of `line`.
+    ///
+    /// If `token` wasn't taken from `line` the column is looked up by searching for it instead.
+    /// An error that was already located within `token` keeps its text and has its column offset
+    /// by where `token` starts.
+    pub fn locate(mut self, line: &str, token: &str) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(line.as_ptr() as usize)
+            .filter(|offset| offset + token.len() <= line.len())
+            .or_else(|| line.find(token));
+
+        if let Some(offset) = offset {
+            let column = line[..offset].chars().count() + 1;
+            self.column = Some(match self.column {
+                Some(inner) => column + inner - 1,
+                None => column,
+            });
+        }
+
+        if self.text.is_none() {
+            self.text = Some(token.to_owned());
+        }
+
+        self
+    }
+
+    /// Attribute the error to `line`.
+    ///
+    /// When the error already has a line it is taken to be relative to a block of input starting
+    /// at `line`, so nested parsers can each report where their own piece of input starts.
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = Some(match self.line {
+            Some(inner) => line + inner - 1,
+            None => line,
+        });
+        self
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {}, column {}: ", line, column)?,
+            (Some(line), None) => write!(f, "line {}: ", line)?,
+            (None, Some(column)) => write!(f, "column {}: ", column)?,
+            (None, None) => (),
+        }
+
+        write!(f, "{}", self.message)?;
+
+        match &self.text {
+            Some(text) => write!(f, ": {:?}", text),
+            None => Ok(()),
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate_works() {
+        let line = "move 1 from x to 3";
+        let token = line.split(' ').nth(3).unwrap();
+        let err = ParseError::new("invalid number").locate(line, token);
+
+        assert_eq!(err.column(), Some(13));
+        assert_eq!(err.text(), Some("x"));
+
+        let line = "2-4,6-x";
+        let section = &line[4..];
+        let err = ParseError::new("invalid number")
+            .locate(section, &section[2..])
+            .locate(line, section);
+
+        assert_eq!(err.column(), Some(7));
+        assert_eq!(err.text(), Some("x"));
+    }
+
+    #[test]
+    fn on_line_works() {
+        let err = ParseError::new("invalid number").on_line(2).on_line(11);
+
+        assert_eq!(err.line(), Some(12));
+    }
+
+    #[test]
+    fn display_works() {
+        let err = ParseError::new("invalid opponent hand")
+            .locate("Q Y", "Q")
+            .on_line(3);
+
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 1: invalid opponent hand: \"Q\""
+        );
+    }
+}
@@ -0,0 +1,333 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{ParseError, Result};
+
+/// A cell of a [`Grid`] as `(row, column)`, both starting at 0 in the top left corner.
+pub type Position = (usize, usize);
+
+const OFFSETS_4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+const OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A dense rectangular grid, stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Build a grid out of `cells` given row by row.
+    ///
+    /// Panics if `cells` can't be split into rows of `width` cells.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        let height = match width {
+            0 => 0,
+            width => cells.len() / width,
+        };
+        assert_eq!(
+            width * height,
+            cells.len(),
+            "{} cells don't fit in rows of {}",
+            cells.len(),
+            width
+        );
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parse one cell per character of `input`, one row per line.
+    ///
+    /// Every line has to be as long as the first one. Errors returned by `f` are located at the
+    /// character it was given.
+    pub fn from_chars<F>(input: &str, f: F) -> Result<Self>
+    where
+        F: FnMut(Position, char) -> Result<T>,
+    {
+        Self::parse(input, None, f)
+    }
+
+    /// Like [`Grid::from_chars`], but short lines are padded with `pad` up to the longest line.
+    pub fn from_chars_padded<F>(input: &str, pad: char, f: F) -> Result<Self>
+    where
+        F: FnMut(Position, char) -> Result<T>,
+    {
+        Self::parse(input, Some(pad), f)
+    }
+
+    fn parse<F>(input: &str, pad: Option<char>, mut f: F) -> Result<Self>
+    where
+        F: FnMut(Position, char) -> Result<T>,
+    {
+        let width = match pad {
+            Some(_) => input.lines().map(|line| line.chars().count()).max(),
+            None => input.lines().next().map(|line| line.chars().count()),
+        };
+        let width = width.unwrap_or(0);
+        let mut cells = Vec::new();
+        let mut height = 0;
+
+        for (row, line) in input.lines().enumerate() {
+            let len = line.chars().count();
+            if pad.is_none() && len != width {
+                return Err(
+                    ParseError::new(format!("expected {} characters in every row", width))
+                        .with_text(line)
+                        .on_line(row + 1),
+                );
+            }
+
+            for (column, (i, ch)) in line.char_indices().enumerate() {
+                let cell = f((row, column), ch).map_err(|err| {
+                    err.locate(line, &line[i..i + ch.len_utf8()])
+                        .on_line(row + 1)
+                })?;
+                cells.push(cell);
+            }
+
+            if let Some(pad) = pad {
+                for column in len..width {
+                    cells.push(f((row, column), pad).map_err(|err| err.on_line(row + 1))?);
+                }
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        pos.0 < self.height && pos.1 < self.width
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    fn index_of(&self, pos: Position) -> Option<usize> {
+        self.contains(pos).then(|| pos.0 * self.width + pos.1)
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i / width, i % width), cell))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| (row, column)))
+    }
+
+    /// The position of the first cell, row by row, for which `f` returns true.
+    pub fn position<F>(&self, mut f: F) -> Option<Position>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter().find(|(_, cell)| f(cell)).map(|(pos, _)| pos)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        // `chunks_exact(0)` panics, an empty grid simply has no rows
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    /// The cells of `column`, from top to bottom.
+    pub fn column(&self, column: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(column < self.width, "column {} out of bounds", column);
+        self.cells[column..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|column| self.column(column))
+    }
+
+    /// The positions above, left, right and below `pos` that are inside the grid.
+    pub fn neighbours4(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        self.offset_all(pos, &OFFSETS_4)
+    }
+
+    /// Like [`Grid::neighbours4`], but including the diagonals.
+    pub fn neighbours8(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        self.offset_all(pos, &OFFSETS_8)
+    }
+
+    fn offset_all<'a>(
+        &'a self,
+        pos: Position,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Position> + 'a {
+        offsets.iter().filter_map(move |(d_row, d_column)| {
+            let neighbour = (
+                pos.0.checked_add_signed(*d_row)?,
+                pos.1.checked_add_signed(*d_column)?,
+            );
+            self.contains(neighbour).then_some(neighbour)
+        })
+    }
+
+    /// A grid of the same size with `f` applied to every cell.
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &Self::Output {
+        match self.index_of(pos) {
+            Some(i) => &self.cells[i],
+            None => panic!(
+                "{:?} is outside of the {}x{} grid",
+                pos, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut Self::Output {
+        match self.index_of(pos) {
+            Some(i) => &mut self.cells[i],
+            None => panic!(
+                "{:?} is outside of the {}x{} grid",
+                pos, self.width, self.height
+            ),
+        }
+    }
+}
+
+/// Writes every row on its own line, without a newline after the last one.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>> {
+        Grid::from_chars(input, |_, ch| {
+            ch.to_digit(10)
+                .ok_or_else(|| ParseError::new("not a digit"))
+        })
+    }
+
+    #[test]
+    fn from_chars_works() {
+        let grid = digits("123\n456").unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 2)], 6);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.to_string(), "123\n456");
+
+        let err = digits("123\n4x6").unwrap_err();
+        assert_eq!(err.to_string(), "line 2, column 2: not a digit: \"x\"");
+        assert_eq!(digits("123\n45").unwrap_err().line(), Some(2));
+
+        let padded = Grid::from_chars_padded(" a\nbcd\n", ' ', |_, ch| Ok(ch)).unwrap();
+        assert_eq!(padded.to_string(), " a \nbcd");
+    }
+
+    #[test]
+    fn rows_and_columns_work() {
+        let grid = digits("123\n456").unwrap();
+
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(
+            grid.column(2).rev().copied().collect::<Vec<_>>(),
+            vec![6, 3]
+        );
+        assert_eq!(grid.columns().count(), 3);
+    }
+
+    #[test]
+    fn neighbours_work() {
+        let grid = Grid::new(3, 3, 0);
+
+        assert_eq!(
+            grid.neighbours4((0, 1)).collect::<Vec<_>>(),
+            vec![(0, 0), (0, 2), (1, 1)]
+        );
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+        assert_eq!(
+            grid.neighbours8((2, 2)).collect::<Vec<_>>(),
+            vec![(1, 1), (1, 2), (2, 1)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+    }
+}
@@ -0,0 +1,123 @@
+use std::{
+    env, fmt,
+    fs::read_to_string,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    process,
+};
+
+use crate::Solution;
+
+/// Where to read the puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The day's own `data/input.txt`
+    Default,
+    /// The day's bundled `data/demo.txt`
+    Demo,
+    Stdin,
+    Path(PathBuf),
+}
+
+impl InputSource {
+    /// `-` stands for stdin, anything else is a path to read.
+    pub fn from_path(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        if path == Path::new("-") {
+            InputSource::Stdin
+        } else {
+            InputSource::Path(path)
+        }
+    }
+
+    /// Parse the arguments of a day's binary: either nothing, `--demo`, `-` or a path.
+    pub fn from_args<I>(args: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut args = args.into_iter();
+        let source = match args.next() {
+            None => InputSource::Default,
+            Some(arg) if arg == "--demo" => InputSource::Demo,
+            Some(arg) if arg.starts_with("--") => return Err(format!("Unknown option {}", arg)),
+            Some(arg) => InputSource::from_path(arg),
+        };
+
+        match args.next() {
+            Some(arg) => Err(format!("Unexpected argument {}", arg)),
+            None => Ok(source),
+        }
+    }
+
+    /// Read the whole input, resolving the default and demo inputs in `day_dir`.
+    pub fn read(&self, day_dir: &Path) -> Result<String, String> {
+        let path = match self {
+            InputSource::Stdin => {
+                let mut input = String::new();
+                return io::stdin()
+                    .read_to_string(&mut input)
+                    .map(|_| input)
+                    .map_err(|err| format!("Couldn't read stdin: {}", err));
+            }
+            InputSource::Default => day_dir.join("data").join("input.txt"),
+            InputSource::Demo => day_dir.join("data").join("demo.txt"),
+            InputSource::Path(path) => path.clone(),
+        };
+
+        read_to_string(&path).map_err(|err| format!("Couldn't read {}: {}", path.display(), err))
+    }
+}
+
+/// Read the input selected on the command line of a day's binary.
+///
+/// `day_dir` should be the crate's `CARGO_MANIFEST_DIR`. Exits the process when the input can't
+/// be read.
+pub fn load_input(day_dir: &str) -> String {
+    let res = InputSource::from_args(env::args().skip(1))
+        .and_then(|source| source.read(Path::new(day_dir)));
+
+    res.unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        eprintln!("Usage: [--demo | - | <path>]");
+        process::exit(1);
+    })
+}
+
+/// Read and parse the input selected on the command line of a day's binary.
+///
+/// Exits the process when the input can't be read, or with the location of the error when it
+/// can't be parsed.
+pub fn load_parsed<S: Solution>(day_dir: &str) -> S::Input {
+    S::parse(&load_input(day_dir)).unwrap_or_else(|err| exit_with(err))
+}
+
+/// Print `err` and exit the process with a failure status.
+pub fn exit_with(err: impl fmt::Display) -> ! {
+    eprintln!("{}", err);
+    process::exit(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn from_args_works() {
+        assert_eq!(InputSource::from_args(args(&[])), Ok(InputSource::Default));
+        assert_eq!(
+            InputSource::from_args(args(&["--demo"])),
+            Ok(InputSource::Demo)
+        );
+        assert_eq!(InputSource::from_args(args(&["-"])), Ok(InputSource::Stdin));
+        assert_eq!(
+            InputSource::from_args(args(&["in.txt"])),
+            Ok(InputSource::Path(PathBuf::from("in.txt")))
+        );
+        assert!(InputSource::from_args(args(&["--real"])).is_err());
+        assert!(InputSource::from_args(args(&["a.txt", "b.txt"])).is_err());
+    }
+}
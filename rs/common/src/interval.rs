@@ -0,0 +1,250 @@
+use std::{iter::Sum, ops::Range, ops::Sub};
+
+/// A set of values stored as sorted, disjoint half-open ranges.
+///
+/// Ranges that overlap or touch are merged on insertion, so `1..3` and `3..5` are kept as `1..5`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: Ord> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: Vec::new() }
+    }
+}
+
+impl<T: Ord + Clone> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add every value of `range`, merging it with the ranges it overlaps or touches.
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        // ranges before `first` end before `range` starts, ranges from `last` on start after it
+        let first = self.ranges.partition_point(|other| other.end < range.start);
+        let last = self
+            .ranges
+            .partition_point(|other| other.start <= range.end);
+
+        let mut merged = range;
+        if first < last {
+            if self.ranges[first].start < merged.start {
+                merged.start = self.ranges[first].start.clone();
+            }
+            if self.ranges[last - 1].end > merged.end {
+                merged.end = self.ranges[last - 1].end.clone();
+            }
+        }
+
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let i = self.ranges.partition_point(|range| range.end <= *value);
+        self.ranges
+            .get(i)
+            .is_some_and(|range| range.start <= *value)
+    }
+
+    /// Whether every value of `range` is in the set.
+    pub fn contains_range(&self, range: &Range<T>) -> bool {
+        if range.is_empty() {
+            return true;
+        }
+
+        let i = self
+            .ranges
+            .partition_point(|other| other.end <= range.start);
+        self.ranges
+            .get(i)
+            .is_some_and(|other| other.start <= range.start && range.end <= other.end)
+    }
+
+    /// Whether any value of `range` is in the set.
+    pub fn overlaps(&self, range: &Range<T>) -> bool {
+        let i = self
+            .ranges
+            .partition_point(|other| other.end <= range.start);
+        self.ranges
+            .get(i)
+            .is_some_and(|other| other.start < range.end && !range.is_empty())
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut res = self.clone();
+        for range in other.iter() {
+            res.insert(range.clone());
+        }
+
+        res
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut res = Self::new();
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let start = a.start.clone().max(b.start.clone());
+            let end = a.end.clone().min(b.end.clone());
+            if start < end {
+                res.ranges.push(start..end);
+            }
+
+            // whichever range ends first can't intersect anything else
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        res
+    }
+
+    /// The values in `self` that aren't in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut res = Self::new();
+        let mut j = 0;
+
+        for range in self.iter() {
+            let mut start = range.start.clone();
+
+            // skip the ranges of `other` that end before this one starts
+            while other.ranges.get(j).is_some_and(|cut| cut.end <= start) {
+                j += 1;
+            }
+
+            let mut k = j;
+            while let Some(cut) = other.ranges.get(k).filter(|cut| cut.start < range.end) {
+                if start < cut.start {
+                    res.ranges.push(start.clone()..cut.start.clone());
+                }
+                if cut.end > start {
+                    start = cut.end.clone();
+                }
+                k += 1;
+            }
+
+            if start < range.end {
+                res.ranges.push(start..range.end.clone());
+            }
+        }
+
+        res
+    }
+
+    /// The values within `bounds` that aren't in the set.
+    pub fn complement(&self, bounds: Range<T>) -> Self {
+        Self::from(bounds).difference(self)
+    }
+
+    /// The ranges missing between the first and the last range of the set.
+    pub fn gaps(&self) -> impl Iterator<Item = Range<T>> + '_ {
+        self.ranges
+            .windows(2)
+            .map(|pair| pair[0].end.clone()..pair[1].start.clone())
+    }
+
+    /// The number of values in the set.
+    pub fn covered_len(&self) -> T
+    where
+        T: Sub<Output = T> + Sum,
+    {
+        self.iter()
+            .map(|range| range.end.clone() - range.start.clone())
+            .sum()
+    }
+
+    /// The ranges of the set, from lowest to highest.
+    pub fn iter(&self) -> impl Iterator<Item = &Range<T>> {
+        self.ranges.iter()
+    }
+
+    /// The number of disjoint ranges in the set.
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+}
+
+impl<T: Ord + Clone> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        let mut res = Self::new();
+        res.insert(range);
+        res
+    }
+}
+
+impl<T: Ord + Clone> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut res = Self::new();
+        for range in iter {
+            res.insert(range);
+        }
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[Range<i32>]) -> IntervalSet<i32> {
+        ranges.iter().cloned().collect()
+    }
+
+    fn ranges(set: &IntervalSet<i32>) -> Vec<Range<i32>> {
+        set.iter().cloned().collect()
+    }
+
+    #[test]
+    fn insert_works() {
+        assert_eq!(ranges(&set(&[5..7, 1..3, 2..4])), [1..4, 5..7]);
+        // touching ranges are merged too
+        assert_eq!(set(&[1..3, 3..5]), IntervalSet::from(1..5));
+        assert_eq!(set(&[1..3, 6..8, 10..12, 2..11]), IntervalSet::from(1..12));
+        assert_eq!(set(&[1..3, 4..4]), IntervalSet::from(1..3));
+    }
+
+    #[test]
+    fn contains_works() {
+        let set = set(&[1..4, 6..8]);
+
+        assert!(set.contains(&1) && set.contains(&3) && set.contains(&6));
+        assert!(!set.contains(&4) && !set.contains(&0) && !set.contains(&8));
+        assert!(set.contains_range(&(2..4)));
+        assert!(!set.contains_range(&(2..7)));
+        assert!(set.overlaps(&(3..5)));
+        assert!(!set.overlaps(&(4..6)));
+    }
+
+    #[test]
+    fn set_operations_work() {
+        let a = set(&[0..5, 10..15]);
+        let b = set(&[3..12, 14..20]);
+
+        assert_eq!(a.union(&b), IntervalSet::from(0..20));
+        assert_eq!(ranges(&a.intersection(&b)), [3..5, 10..12, 14..15]);
+        assert_eq!(ranges(&a.difference(&b)), [0..3, 12..14]);
+        assert_eq!(ranges(&b.difference(&a)), [5..10, 15..20]);
+        assert_eq!(ranges(&a.complement(-2..12)), [-2..0, 5..10]);
+    }
+
+    #[test]
+    fn gaps_works() {
+        let set = set(&[0..5, 7..8, 10..15]);
+
+        assert_eq!(set.gaps().collect::<Vec<_>>(), [5..7, 8..10]);
+        assert_eq!(set.covered_len(), 11);
+        assert_eq!(set.len(), 3);
+    }
+}
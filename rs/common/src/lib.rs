@@ -1,3 +1,23 @@
+mod answer;
+mod bench;
+mod error;
+mod grid;
+mod input;
+mod interval;
 mod map2d;
+mod parse;
+mod part;
+mod search;
+mod solution;
 
+pub use answer::*;
+pub use bench::*;
+pub use error::*;
+pub use grid::*;
+pub use input::*;
+pub use interval::*;
 pub use map2d::*;
+pub use parse::*;
+pub use part::*;
+pub use search::*;
+pub use solution::*;
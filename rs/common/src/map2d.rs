@@ -1,14 +1,32 @@
-use std::collections::btree_map::{Entry, Iter};
+use std::collections::btree_map::{Entry, Iter, Range};
 use std::collections::BTreeMap;
-use std::ops::Bound::Included;
+use std::iter;
+use std::ops::{Bound, RangeBounds};
 
 pub type Coord = (isize, isize);
 
-#[derive(Debug)]
+/// A sparse 2D map, ordered by row and then by column.
+#[derive(Debug, Clone, Default)]
 pub struct BTreeMap2D<T> {
     elements: BTreeMap<Coord, T>,
 }
 
+/// Turn `bounds` into an inclusive `(start, end)` pair, or `None` if they contain nothing.
+fn inclusive_bounds(bounds: &impl RangeBounds<isize>) -> Option<(isize, isize)> {
+    let start = match bounds.start_bound() {
+        Bound::Included(start) => *start,
+        Bound::Excluded(start) => start.checked_add(1)?,
+        Bound::Unbounded => isize::MIN,
+    };
+    let end = match bounds.end_bound() {
+        Bound::Included(end) => *end,
+        Bound::Excluded(end) => end.checked_sub(1)?,
+        Bound::Unbounded => isize::MAX,
+    };
+
+    (start <= end).then_some((start, end))
+}
+
 impl<T> BTreeMap2D<T> {
     pub fn new() -> Self {
         Self {
@@ -16,30 +34,102 @@ impl<T> BTreeMap2D<T> {
         }
     }
 
-    // TODO: make range method more generic
-    pub fn range(&self, ne: &Coord) -> impl Iterator<Item = (&Coord, &T)> {
+    /// The elements in `rows` and `columns`, row by row.
+    ///
+    /// Rows without any element are skipped over, so this only costs a lookup per row that is
+    /// actually present in the rectangle.
+    pub fn range_bounds<R, C>(&self, rows: R, columns: C) -> impl Iterator<Item = (&Coord, &T)>
+    where
+        R: RangeBounds<isize>,
+        C: RangeBounds<isize>,
+    {
+        let rows = inclusive_bounds(&rows);
+        let columns = inclusive_bounds(&columns);
+        let (rows, columns) = match rows.zip(columns) {
+            Some(bounds) => bounds,
+            // an empty range: start past the last row so nothing is yielded
+            None => ((isize::MAX, isize::MIN), (0, 0)),
+        };
+
+        let first_row = self.next_row(rows.0);
+        iter::successors(first_row, |row| {
+            row.checked_add(1).and_then(|row| self.next_row(row))
+        })
+        .take_while(move |row| *row <= rows.1)
+        .flat_map(move |row| self.elements.range((row, columns.0)..=(row, columns.1)))
+    }
+
+    /// The elements between the `top_left` and `bottom_right` corners, both included.
+    pub fn range_rect(
+        &self,
+        top_left: &Coord,
+        bottom_right: &Coord,
+    ) -> impl Iterator<Item = (&Coord, &T)> {
+        self.range_bounds(top_left.0..=bottom_right.0, top_left.1..=bottom_right.1)
+    }
+
+    /// Like [`BTreeMap2D::range_rect`], but leaving out the row and column of `bottom_right`.
+    pub fn range_rect_exclusive(
+        &self,
+        top_left: &Coord,
+        bottom_right: &Coord,
+    ) -> impl Iterator<Item = (&Coord, &T)> {
+        self.range_bounds(top_left.0..bottom_right.0, top_left.1..bottom_right.1)
+    }
+
+    /// The first row at or after `row` that has any element.
+    fn next_row(&self, row: isize) -> Option<isize> {
         self.elements
-            .range((Included(ne), Included(&(ne.0, ne.1 + 2))))
-            .chain(
-                self.elements
-                    .range((Included(&(ne.0 + 1, ne.1)), Included(&(ne.0 + 1, ne.1 + 2)))),
-            )
-            .chain(
-                self.elements
-                    .range((Included(&(ne.0 + 2, ne.1)), Included(&(ne.0 + 2, ne.1 + 2)))),
-            )
+            .range((row, isize::MIN)..)
+            .next()
+            .map(|(pos, _)| pos.0)
     }
 
     pub fn neighbours(&self, pos: Coord) -> impl Iterator<Item = (&Coord, &T)> {
-        let ne = (pos.0 - 1, pos.1 - 1);
-        self.range(&ne)
+        self.range_rect(&(pos.0 - 1, pos.1 - 1), &(pos.0 + 1, pos.1 + 1))
             .filter(move |(curr_pos, _)| curr_pos.0 != pos.0 || curr_pos.1 != pos.1)
     }
 
+    /// Every row that has elements, along with those elements.
+    pub fn rows(&self) -> impl Iterator<Item = (isize, Range<'_, Coord, T>)> {
+        iter::successors(self.next_row(isize::MIN), |row| {
+            row.checked_add(1).and_then(|row| self.next_row(row))
+        })
+        .map(|row| {
+            (
+                row,
+                self.elements.range((row, isize::MIN)..=(row, isize::MAX)),
+            )
+        })
+    }
+
+    /// The top left and bottom right corners of the smallest rectangle holding every element.
+    pub fn bounding_box(&self) -> Option<(Coord, Coord)> {
+        let first_row = self.elements.keys().next()?.0;
+        let last_row = self.elements.keys().next_back()?.0;
+
+        let (first_column, last_column) = self
+            .rows()
+            .filter_map(|(_, mut row)| {
+                let first = row.next()?.0 .1;
+                let last = row.next_back().map_or(first, |(pos, _)| pos.1);
+                Some((first, last))
+            })
+            .fold((isize::MAX, isize::MIN), |(min, max), (first, last)| {
+                (min.min(first), max.max(last))
+            });
+
+        Some(((first_row, first_column), (last_row, last_column)))
+    }
+
     pub fn insert(&mut self, key: Coord, value: T) -> Option<T> {
         self.elements.insert(key, value)
     }
 
+    pub fn remove(&mut self, key: &Coord) -> Option<T> {
+        self.elements.remove(key)
+    }
+
     pub fn get(&self, key: &Coord) -> Option<&T> {
         self.elements.get(key)
     }
@@ -48,13 +138,21 @@ impl<T> BTreeMap2D<T> {
         self.elements.get_mut(key)
     }
 
-    pub fn entry(&mut self, key: Coord) -> Entry<(isize, isize), T> {
+    pub fn entry(&mut self, key: Coord) -> Entry<'_, (isize, isize), T> {
         self.elements.entry(key)
     }
 
     pub fn iter(&self) -> Iter<'_, (isize, isize), T> {
         self.elements.iter()
     }
+
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
 }
 
 #[cfg(test)]
@@ -96,4 +194,56 @@ mod tests {
 
         assert_eq!(neighbours, expected);
     }
+
+    fn field() -> BTreeMap2D<usize> {
+        let mut field = BTreeMap2D::new();
+        for (i, pos) in [(-2, 5), (0, 0), (0, 3), (1, 1), (4, -1), (4, 2)]
+            .into_iter()
+            .enumerate()
+        {
+            field.insert(pos, i);
+        }
+
+        field
+    }
+
+    #[test]
+    fn range_rect_works() {
+        let field = field();
+        let values = |iter: &mut dyn Iterator<Item = (&Coord, &usize)>| {
+            iter.map(|(_, value)| *value).collect::<Vec<_>>()
+        };
+
+        assert_eq!(values(&mut field.range_rect(&(0, 0), &(4, 2))), [1, 3, 5]);
+        assert_eq!(
+            values(&mut field.range_rect_exclusive(&(0, 0), &(4, 2))),
+            [1, 3]
+        );
+        assert_eq!(values(&mut field.range_bounds(..1, 3..)), [0, 2]);
+        assert_eq!(values(&mut field.range_bounds(.., ..)), [0, 1, 2, 3, 4, 5]);
+        assert!(field.range_rect(&(3, 0), &(1, 5)).next().is_none());
+        assert!(field.range_bounds(.., 2..2).next().is_none());
+    }
+
+    #[test]
+    fn rows_works() {
+        let field = field();
+        let rows = field
+            .rows()
+            .map(|(row, elements)| (row, elements.count()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(rows, [(-2, 1), (0, 2), (1, 1), (4, 2)]);
+    }
+
+    #[test]
+    fn bounding_box_works() {
+        let mut field = field();
+        assert_eq!(field.bounding_box(), Some(((-2, -1), (4, 5))));
+
+        field.remove(&(-2, 5));
+        assert_eq!(field.len(), 5);
+        assert_eq!(field.bounding_box(), Some(((0, -1), (4, 3))));
+        assert_eq!(BTreeMap2D::<usize>::new().bounding_box(), None);
+    }
 }
@@ -0,0 +1,54 @@
+use std::str::FromStr;
+
+use crate::{ParseError, Result};
+
+/// Parse every line of `input`, attributing errors to the line they were raised on.
+pub fn parse_lines<T, F>(input: &str, mut parse_line: F) -> Result<Vec<T>>
+where
+    F: FnMut(&str) -> Result<T>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_line(line).map_err(|err| err.on_line(i + 1)))
+        .collect()
+}
+
+/// Parse every block of lines of `input`, blocks being separated by an empty line.
+///
+/// Errors are attributed to the block's first line, or offset by it if the block parser already
+/// knows which of its lines is at fault.
+pub fn parse_blocks<T, F>(input: &str, mut parse_block: F) -> Result<Vec<T>>
+where
+    F: FnMut(&str) -> Result<T>,
+{
+    let mut line = 1;
+
+    input
+        .split("\n\n")
+        .map(|block| {
+            let start = line;
+            line += block.lines().count() + 1;
+            parse_block(block).map_err(|err| err.on_line(start))
+        })
+        .collect()
+}
+
+pub fn parse_number<T: FromStr>(text: &str) -> Result<T> {
+    text.parse()
+        .map_err(|_| ParseError::new("invalid number").with_text(text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_blocks_works() {
+        let input = "1\n2\n\n3\nx\n4";
+        let err = parse_blocks(input, |block| parse_lines(block, parse_number::<u32>)).unwrap_err();
+
+        assert_eq!(err.line(), Some(5));
+        assert_eq!(err.text(), Some("x"));
+    }
+}
@@ -0,0 +1,252 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+/// What a search found out about every node it reached.
+#[derive(Debug, Clone)]
+pub struct SearchResult<N> {
+    dist: HashMap<N, u64>,
+    prev: HashMap<N, N>,
+}
+
+impl<N: Clone + Eq + Hash> SearchResult<N> {
+    fn new() -> Self {
+        Self {
+            dist: HashMap::new(),
+            prev: HashMap::new(),
+        }
+    }
+
+    /// The cost of the cheapest path from any source to `node`, if it was reached.
+    pub fn distance(&self, node: &N) -> Option<u64> {
+        self.dist.get(node).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<N, u64> {
+        &self.dist
+    }
+
+    /// The node before each reached node on its cheapest path. Sources have no predecessor.
+    pub fn predecessors(&self) -> &HashMap<N, N> {
+        &self.prev
+    }
+
+    /// The cheapest path from a source to `node`, both included.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.dist.contains_key(node) {
+            return None;
+        }
+
+        let mut path = vec![node.clone()];
+        while let Some(prev) = self.prev.get(path.last().unwrap()) {
+            path.push(prev.clone());
+        }
+        path.reverse();
+
+        Some(path)
+    }
+}
+
+/// Breadth-first search from every node in `sources`, for graphs where every step costs 1.
+pub fn bfs<N, I, F>(sources: impl IntoIterator<Item = N>, mut neighbours: F) -> SearchResult<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+{
+    let mut res = SearchResult::new();
+    let mut queue = VecDeque::new();
+
+    for source in sources {
+        if res.dist.insert(source.clone(), 0).is_none() {
+            queue.push_back(source);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        let dist = res.dist[&node];
+
+        for next in neighbours(&node) {
+            if res.dist.contains_key(&next) {
+                continue;
+            }
+
+            res.dist.insert(next.clone(), dist + 1);
+            res.prev.insert(next.clone(), node.clone());
+            queue.push_back(next);
+        }
+    }
+
+    res
+}
+
+/// Dijkstra's algorithm from every node in `sources`. `neighbours` yields each next node along
+/// with the cost of stepping to it.
+pub fn dijkstra<N, I, F>(sources: impl IntoIterator<Item = N>, neighbours: F) -> SearchResult<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+    F: FnMut(&N) -> I,
+{
+    search(sources, None, neighbours, |_| 0)
+}
+
+/// A* search from `sources` that stops once `goal` is reached.
+///
+/// `heuristic` has to be admissible, i.e. never overestimate the remaining cost to `goal`, for the
+/// distance to `goal` to be the shortest one.
+pub fn astar<N, I, F, H>(
+    sources: impl IntoIterator<Item = N>,
+    goal: &N,
+    neighbours: F,
+    heuristic: H,
+) -> SearchResult<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+    F: FnMut(&N) -> I,
+    H: FnMut(&N) -> u64,
+{
+    search(sources, Some(goal), neighbours, heuristic)
+}
+
+/// An entry of the open set, ordered so that `BinaryHeap` pops the lowest priority first.
+struct Open<N> {
+    priority: u64,
+    cost: u64,
+    node: N,
+}
+
+impl<N> PartialEq for Open<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N> Eq for Open<N> {}
+
+impl<N> PartialOrd for Open<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> Ord for Open<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+fn search<N, I, F, H>(
+    sources: impl IntoIterator<Item = N>,
+    goal: Option<&N>,
+    mut neighbours: F,
+    mut heuristic: H,
+) -> SearchResult<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+    F: FnMut(&N) -> I,
+    H: FnMut(&N) -> u64,
+{
+    let mut res = SearchResult::new();
+    let mut open = BinaryHeap::new();
+
+    for source in sources {
+        res.dist.insert(source.clone(), 0);
+        open.push(Open {
+            priority: heuristic(&source),
+            cost: 0,
+            node: source,
+        });
+    }
+
+    while let Some(Open { cost, node, .. }) = open.pop() {
+        // a cheaper path to `node` was found after this entry was pushed
+        if cost > res.dist[&node] {
+            continue;
+        }
+        if goal == Some(&node) {
+            break;
+        }
+
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+            if res.dist.get(&next).is_some_and(|dist| *dist <= next_cost) {
+                continue;
+            }
+
+            res.dist.insert(next.clone(), next_cost);
+            res.prev.insert(next.clone(), node.clone());
+            open.push(Open {
+                priority: next_cost + heuristic(&next),
+                cost: next_cost,
+                node: next,
+            });
+        }
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a 5x5 room with a wall in column 2 that has a gap in the bottom row
+    fn open_neighbours((row, column): (i32, i32)) -> Vec<(i32, i32)> {
+        [(-1, 0), (0, -1), (0, 1), (1, 0)]
+            .into_iter()
+            .map(|(d_row, d_column)| (row + d_row, column + d_column))
+            .filter(|(row, column)| (0..5).contains(row) && (0..5).contains(column))
+            .filter(|(row, column)| *column != 2 || *row == 4)
+            .collect()
+    }
+
+    #[test]
+    fn bfs_works() {
+        let res = bfs([(0, 0)], |pos| open_neighbours(*pos));
+
+        assert_eq!(res.distance(&(0, 4)), Some(12));
+        assert_eq!(res.distance(&(0, 2)), None);
+        assert_eq!(res.path_to(&(0, 4)).unwrap().len(), 13);
+        assert_eq!(res.path_to(&(0, 0)), Some(vec![(0, 0)]));
+
+        let res = bfs([(0, 0), (0, 4)], |pos| open_neighbours(*pos));
+        assert_eq!(res.distance(&(4, 2)), Some(6));
+    }
+
+    #[test]
+    fn dijkstra_works() {
+        // the direct road from a to c is longer than going through b
+        let roads = |node: &char| match node {
+            'a' => vec![('b', 2), ('c', 7)],
+            'b' => vec![('c', 3), ('d', 9)],
+            'c' => vec![('d', 1)],
+            _ => vec![],
+        };
+        let res = dijkstra(['a'], roads);
+
+        assert_eq!(res.distance(&'c'), Some(5));
+        assert_eq!(res.distance(&'d'), Some(6));
+        assert_eq!(res.path_to(&'d'), Some(vec!['a', 'b', 'c', 'd']));
+        assert_eq!(res.predecessors().get(&'a'), None);
+    }
+
+    #[test]
+    fn astar_works() {
+        let goal = (0, 4);
+        let res = astar(
+            [(0, 0)],
+            &goal,
+            |pos| open_neighbours(*pos).into_iter().map(|next| (next, 1)),
+            |pos| pos.0.abs_diff(goal.0) as u64 + pos.1.abs_diff(goal.1) as u64,
+        );
+        let path = res.path_to(&goal).unwrap();
+
+        assert_eq!(res.distance(&goal), Some(12));
+        assert_eq!((path[0], path[12]), ((0, 0), goal));
+    }
+}
@@ -0,0 +1,20 @@
+use crate::{Answer, Part, Result};
+
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    fn part2(input: &Self::Input) -> Answer;
+}
+
+pub fn solve<S: Solution>(input: &str, part: Part) -> Result<Answer> {
+    let parsed = S::parse(input)?;
+
+    Ok(match part {
+        Part::One => S::part1(&parsed),
+        Part::Two => S::part2(&parsed),
+    })
+}
@@ -0,0 +1,37 @@
+use aoc_common::{parse_blocks, parse_lines, parse_number, Answer, Result, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    /// Calories carried by each elf, in descending order
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut calories = parse_blocks(input, |group| {
+            let items: Vec<u32> = parse_lines(group, parse_number)?;
+            Ok(items.iter().sum::<u32>())
+        })?;
+
+        // descending sort
+        calories.sort_by(|a, b| b.cmp(a));
+
+        Ok(calories)
+    }
+
+    fn part1(calories: &Self::Input) -> Answer {
+        calories
+            .first()
+            .copied()
+            .map_or(Answer::NoSolution, Answer::from)
+    }
+
+    /// There's no answer with fewer than three elves.
+    fn part2(calories: &Self::Input) -> Answer {
+        calories.get(..3).map_or(Answer::NoSolution, |top| {
+            top.iter()
+                .map(|calories| u64::from(*calories))
+                .sum::<u64>()
+                .into()
+        })
+    }
+}
@@ -1,18 +1,9 @@
-use std::{fs::read_to_string, path::Path};
+use aoc_common::{load_parsed, Solution};
+use q_1::Day1;
 
 fn main() {
-    let path = Path::new("./data/input.txt");
-    let input = read_to_string(&path).unwrap();
-    let mut calories = input
-        .split("\n\n")
-        .map(|group| group.lines().map(|line| line.parse::<u32>().unwrap()).sum())
-        .collect::<Vec<u32>>();
+    let calories = load_parsed::<Day1>(env!("CARGO_MANIFEST_DIR"));
 
-    // descending sort
-    calories.sort_by(|a, b| b.cmp(a));
-
-    // part 1
-    println!("{:#?}", calories[0]);
-    // part 2
-    println!("{:#?}", calories[0] + calories[1] + calories[2]);
+    println!("{}", Day1::part1(&calories));
+    println!("{}", Day1::part2(&calories));
 }
@@ -0,0 +1,384 @@
+use std::fmt;
+
+use aoc_common::{parse_number, ParseError, Result};
+
+/// The registers an instruction can change. They wrap around on overflow, like hardware ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Registers {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Default for Registers {
+    fn default() -> Self {
+        Self { x: 1, y: 0 }
+    }
+}
+
+/// Where to go once an instruction is done.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Flow {
+    Next,
+    /// Move the program counter by this many instructions
+    Jump(i32),
+}
+
+/// What the CPU knows about an instruction.
+#[derive(Debug)]
+pub struct Opcode {
+    pub mnemonic: &'static str,
+    pub has_operand: bool,
+    /// How many cycles the instruction takes, its effect only shows after the last one
+    pub cycles: usize,
+    run: fn(&mut Registers, i32) -> Flow,
+}
+
+/// The instruction set, adding an instruction only takes a new entry here.
+pub const OPCODES: &[Opcode] = &[
+    Opcode {
+        mnemonic: "noop",
+        has_operand: false,
+        cycles: 1,
+        run: |_, _| Flow::Next,
+    },
+    Opcode {
+        mnemonic: "addx",
+        has_operand: true,
+        cycles: 2,
+        run: |registers, value| {
+            registers.x = registers.x.wrapping_add(value);
+            Flow::Next
+        },
+    },
+    Opcode {
+        mnemonic: "addy",
+        has_operand: true,
+        cycles: 2,
+        run: |registers, value| {
+            registers.y = registers.y.wrapping_add(value);
+            Flow::Next
+        },
+    },
+    Opcode {
+        mnemonic: "mulx",
+        has_operand: true,
+        cycles: 3,
+        run: |registers, value| {
+            registers.x = registers.x.wrapping_mul(value);
+            Flow::Next
+        },
+    },
+    Opcode {
+        mnemonic: "jmp",
+        has_operand: true,
+        cycles: 1,
+        run: |_, offset| Flow::Jump(offset),
+    },
+    Opcode {
+        mnemonic: "jnz",
+        has_operand: true,
+        cycles: 2,
+        run: |registers, offset| match registers.x {
+            0 => Flow::Next,
+            _ => Flow::Jump(offset),
+        },
+    },
+];
+
+#[derive(Clone, Copy)]
+pub struct Instruction {
+    opcode: &'static Opcode,
+    operand: i32,
+}
+
+impl Instruction {
+    /// The instruction called `mnemonic`, the operand is ignored if it doesn't take one.
+    pub fn new(mnemonic: &str, operand: i32) -> Option<Self> {
+        let opcode = OPCODES.iter().find(|opcode| opcode.mnemonic == mnemonic)?;
+        let operand = if opcode.has_operand { operand } else { 0 };
+
+        Some(Self { opcode, operand })
+    }
+
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut parts = input.split(' ');
+        let mnemonic = parts.next().unwrap_or_default();
+        let opcode = OPCODES
+            .iter()
+            .find(|opcode| opcode.mnemonic == mnemonic)
+            .ok_or_else(|| ParseError::new("unknown instruction").with_text(input))?;
+
+        let operand = match (opcode.has_operand, parts.next()) {
+            (true, Some(value)) => parse_number(value).map_err(|err| err.locate(input, value))?,
+            (true, None) => {
+                return Err(ParseError::new("expected an operand").with_text(input));
+            }
+            (false, Some(extra)) => {
+                return Err(ParseError::new("unexpected operand").locate(input, extra));
+            }
+            (false, None) => 0,
+        };
+
+        match parts.next() {
+            Some(extra) => Err(ParseError::new("unexpected operand").locate(input, extra)),
+            None => Ok(Self { opcode, operand }),
+        }
+    }
+
+    pub fn opcode(&self) -> &'static Opcode {
+        self.opcode
+    }
+
+    pub fn operand(&self) -> i32 {
+        self.operand
+    }
+
+    pub fn cycles(&self) -> usize {
+        self.opcode.cycles
+    }
+}
+
+impl PartialEq for Instruction {
+    fn eq(&self, other: &Self) -> bool {
+        self.opcode.mnemonic == other.opcode.mnemonic && self.operand == other.operand
+    }
+}
+
+impl Eq for Instruction {}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.opcode.has_operand {
+            true => write!(f, "{} {}", self.opcode.mnemonic, self.operand),
+            false => write!(f, "{}", self.opcode.mnemonic),
+        }
+    }
+}
+
+impl fmt::Debug for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+/// What happened during a single cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CycleEvent {
+    /// Counting from 1
+    pub cycle: usize,
+    pub x_during: i32,
+    /// X once the cycle is over, only different if an instruction finished
+    pub x_after: i32,
+    /// The instruction running during the cycle
+    pub pc: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct Cpu {
+    program: Vec<Instruction>,
+    /// How many cycles are over
+    cycle: usize,
+    registers: Registers,
+    pc: usize,
+    /// How many cycles the current instruction already took
+    elapsed: usize,
+}
+
+impl Cpu {
+    pub fn new(program: &[Instruction]) -> Self {
+        Self {
+            program: program.to_vec(),
+            cycle: 0,
+            registers: Registers::default(),
+            pc: 0,
+            elapsed: 0,
+        }
+    }
+
+    /// Run a single cycle, or return `None` once the program counter left the program.
+    pub fn step(&mut self) -> Option<CycleEvent> {
+        let instruction = *self.program.get(self.pc)?;
+        let (pc, x_during) = (self.pc, self.registers.x);
+
+        self.cycle += 1;
+        self.elapsed += 1;
+        if self.elapsed == instruction.cycles() {
+            self.elapsed = 0;
+            self.pc = match (instruction.opcode.run)(&mut self.registers, instruction.operand) {
+                Flow::Next => self.pc + 1,
+                // jumping before the start halts the CPU just like jumping past the end
+                Flow::Jump(offset) => self
+                    .pc
+                    .checked_add_signed(offset as isize)
+                    .unwrap_or(usize::MAX),
+            };
+        }
+
+        Some(CycleEvent {
+            cycle: self.cycle,
+            x_during,
+            x_after: self.registers.x,
+            pc,
+        })
+    }
+
+    /// Go back to the start of the program.
+    pub fn reset(&mut self) {
+        *self = Self::new(&self.program);
+    }
+
+    pub fn program(&self) -> &[Instruction] {
+        &self.program
+    }
+
+    pub fn cycle(&self) -> usize {
+        self.cycle
+    }
+
+    pub fn registers(&self) -> Registers {
+        self.registers
+    }
+
+    pub fn x(&self) -> i32 {
+        self.registers.x
+    }
+
+    /// The instruction running during the next cycle.
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    /// How many cycles of the current instruction already ran.
+    pub fn elapsed(&self) -> usize {
+        self.elapsed
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.pc >= self.program.len()
+    }
+
+    /// Every remaining cycle until the program stops, which may never happen.
+    pub fn events(&mut self) -> impl Iterator<Item = CycleEvent> + '_ {
+        std::iter::from_fn(|| self.step())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn program(lines: &[&str]) -> Vec<Instruction> {
+        lines
+            .iter()
+            .map(|line| Instruction::parse(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn next_tick_works() {
+        let mut cpu = Cpu::new(&program(&["noop", "addx 3", "addx -5"]));
+
+        // cycle, X and program counter once every cycle is over
+        let expected = [(1, 1, 1), (2, 1, 1), (3, 4, 2), (4, 4, 2), (5, -1, 3)];
+        for (cycle, x, pc) in expected {
+            cpu.step().unwrap();
+            assert_eq!(cpu.cycle(), cycle);
+            assert_eq!(cpu.x(), x);
+            assert_eq!(cpu.pc(), pc);
+        }
+        assert_eq!(cpu.step(), None);
+        assert!(cpu.is_halted());
+    }
+
+    #[test]
+    fn step_works() {
+        let mut cpu = Cpu::new(&program(&["noop", "addx 3"]));
+
+        let events: Vec<_> = cpu.events().collect();
+        assert_eq!(
+            events,
+            [
+                CycleEvent {
+                    cycle: 1,
+                    x_during: 1,
+                    x_after: 1,
+                    pc: 0
+                },
+                CycleEvent {
+                    cycle: 2,
+                    x_during: 1,
+                    x_after: 1,
+                    pc: 1
+                },
+                CycleEvent {
+                    cycle: 3,
+                    x_during: 1,
+                    x_after: 4,
+                    pc: 1
+                },
+            ]
+        );
+
+        cpu.reset();
+        assert_eq!((cpu.cycle(), cpu.x(), cpu.pc()), (0, 1, 0));
+    }
+
+    #[test]
+    fn instructions_work() {
+        // y counts the loops while x goes down from 3 to 0, then the jump skips `addx 100`
+        let mut cpu = Cpu::new(&program(&[
+            "addx 2", "addy 1", "addx -1", "jnz -2", "mulx 5", "jmp 2", "addx 100", "noop",
+        ]));
+        let events: Vec<_> = cpu.events().collect();
+
+        assert_eq!(cpu.registers(), Registers { x: 0, y: 3 });
+        assert_eq!(
+            events.iter().map(|event| event.pc).collect::<Vec<_>>(),
+            [0, 0, 1, 1, 2, 2, 3, 3, 1, 1, 2, 2, 3, 3, 1, 1, 2, 2, 3, 3, 4, 4, 4, 5, 7]
+        );
+
+        let mut cpu = Cpu::new(&program(&["jmp -1"]));
+        assert!(cpu.step().is_some());
+        assert!(cpu.is_halted());
+    }
+
+    #[test]
+    fn overflow_wraps() {
+        let mut cpu = Cpu::new(&program(&[
+            "addx 2147483646",
+            "addx 3",
+            "mulx 2",
+            "addy -5",
+        ]));
+        cpu.events().for_each(drop);
+
+        assert_eq!(
+            cpu.registers(),
+            Registers {
+                x: i32::MIN.wrapping_add(2).wrapping_mul(2),
+                y: -5
+            }
+        );
+    }
+
+    #[test]
+    fn instruction_parse_works() {
+        assert_eq!(
+            Instruction::parse("noop"),
+            Ok(Instruction::new("noop", 0).unwrap())
+        );
+        assert_eq!(
+            Instruction::parse("addx 15"),
+            Ok(Instruction::new("addx", 15).unwrap())
+        );
+        assert_eq!(
+            Instruction::parse("addx -11").unwrap().to_string(),
+            "addx -11"
+        );
+        assert_eq!(Instruction::parse("jnz -2").unwrap().cycles(), 2);
+        assert!(Instruction::parse("subx 3").is_err());
+        assert!(Instruction::parse("addx").is_err());
+        assert!(Instruction::parse("noop 1").is_err());
+        assert!(Instruction::parse("addx 1 2").is_err());
+    }
+}
@@ -0,0 +1,278 @@
+use std::io::{self, Write};
+
+use aoc_common::Grid;
+
+use crate::CycleEvent;
+
+/// The shape of the screen, the sprite and how pixels are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrtConfig {
+    pub width: usize,
+    pub height: usize,
+    /// How many pixels the sprite covers, centered on X
+    pub sprite_width: usize,
+    pub lit: char,
+    pub unlit: char,
+}
+
+impl CrtConfig {
+    /// The screen of the puzzle.
+    pub const PUZZLE: CrtConfig = CrtConfig {
+        width: 40,
+        height: 6,
+        sprite_width: 3,
+        lit: '#',
+        unlit: '.',
+    };
+
+    /// How many cycles it takes to draw the whole screen.
+    pub fn pixels(&self) -> usize {
+        self.width * self.height
+    }
+}
+
+/// Gray levels of the exported images.
+const PGM_LIT: u8 = 255;
+const PGM_UNLIT: u8 = 0;
+/// Pixels the beam didn't reach yet, so that animation frames show its progress
+const PGM_UNDRAWN: u8 = 48;
+
+#[derive(Debug, Clone)]
+pub struct Crt {
+    config: CrtConfig,
+    screen: Grid<bool>,
+    /// Index of the next pixel to draw, row by row
+    cursor: usize,
+}
+
+impl Crt {
+    /// Fails on a screen without columns, which has nowhere to draw its pixels.
+    pub fn new(config: CrtConfig) -> Result<Self, String> {
+        if config.width == 0 {
+            return Err("the screen has to be at least 1 pixel wide".to_owned());
+        }
+
+        Ok(Self::blank(config))
+    }
+
+    fn blank(config: CrtConfig) -> Self {
+        Self {
+            config,
+            screen: Grid::new(config.width, config.height, false),
+            cursor: 0,
+        }
+    }
+
+    /// Turn every pixel off and start drawing from the top left again.
+    pub fn clear(&mut self) {
+        *self = Self::blank(self.config);
+    }
+
+    pub fn config(&self) -> &CrtConfig {
+        &self.config
+    }
+
+    pub fn screen(&self) -> &Grid<bool> {
+        &self.screen
+    }
+
+    pub fn add_pixel(&mut self, is_lit: bool) {
+        let pos = (
+            self.cursor / self.config.width,
+            self.cursor % self.config.width,
+        );
+        // anything drawn past the last row falls off the screen
+        if let Some(pixel) = self.screen.get_mut(pos) {
+            *pixel = is_lit;
+        }
+
+        self.cursor += 1;
+    }
+
+    pub fn get_current_idx(&self) -> usize {
+        self.cursor % self.config.width
+    }
+
+    /// Draw the pixel of `event`'s cycle, lit if the sprite centered on X covers it.
+    pub fn draw(&mut self, event: &CycleEvent) {
+        let draw_idx = self.get_current_idx() as i64;
+        let sprite_width = self.config.sprite_width as i64;
+        let left = event.x_during as i64 - (sprite_width - 1) / 2;
+        self.add_pixel((left..left + sprite_width).contains(&draw_idx));
+    }
+
+    fn glyph(&self, is_lit: bool) -> char {
+        match is_lit {
+            true => self.config.lit,
+            false => self.config.unlit,
+        }
+    }
+
+    /// The rows drawn so far, the last one possibly unfinished.
+    pub fn drawn(&self) -> String {
+        let width = self.config.width;
+        let mut res = String::new();
+        for i in 0..self.cursor.min(self.config.pixels()) {
+            res.push(self.glyph(self.screen[(i / width, i % width)]));
+            if i % width == width - 1 {
+                res.push('\n');
+            }
+        }
+        if !res.is_empty() && !res.ends_with('\n') {
+            res.push('\n');
+        }
+
+        res
+    }
+
+    pub fn print(&self) -> String {
+        let screen = self.screen.map(|is_lit| self.glyph(*is_lit));
+
+        format!("{}\n", screen)
+    }
+
+    /// Write the screen as a binary grayscale PGM, every pixel a `scale` pixels wide square.
+    pub fn write_pgm(&self, scale: usize, mut out: impl Write) -> io::Result<()> {
+        let (width, height) = (self.config.width, self.config.height);
+        write!(out, "P5\n{} {}\n255\n", width * scale, height * scale)?;
+
+        let mut bytes = Vec::with_capacity(width * height * scale * scale);
+        for row in 0..height {
+            let line: Vec<_> = (0..width)
+                .flat_map(|column| {
+                    let is_drawn = row * width + column < self.cursor;
+                    let level = match (is_drawn, self.screen[(row, column)]) {
+                        (false, _) => PGM_UNDRAWN,
+                        (true, true) => PGM_LIT,
+                        (true, false) => PGM_UNLIT,
+                    };
+                    std::iter::repeat_n(level, scale)
+                })
+                .collect();
+            for _ in 0..scale {
+                bytes.extend_from_slice(&line);
+            }
+        }
+
+        out.write_all(&bytes)
+    }
+}
+
+impl Default for Crt {
+    /// A blank screen of the puzzle.
+    fn default() -> Self {
+        Self::blank(CrtConfig::PUZZLE)
+    }
+}
+
+/// Run `events` on `crt`, handing the screen over after every cycle.
+///
+/// Stops once the screen is full, so that a program looping forever still ends.
+pub fn animate<E, F>(
+    mut crt: Crt,
+    events: impl Iterator<Item = CycleEvent>,
+    mut frame: F,
+) -> Result<Crt, E>
+where
+    F: FnMut(&CycleEvent, &Crt) -> Result<(), E>,
+{
+    for event in events.take(crt.config().pixels()) {
+        crt.draw(&event);
+        frame(&event, &crt)?;
+    }
+
+    Ok(crt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, Cpu};
+
+    const DEMO: &str = include_str!("../data/demo.txt");
+
+    fn event(x: i32) -> CycleEvent {
+        CycleEvent {
+            cycle: 1,
+            x_during: x,
+            x_after: x,
+            pc: 0,
+        }
+    }
+
+    #[test]
+    fn config_works() {
+        let config = CrtConfig {
+            width: 8,
+            height: 2,
+            sprite_width: 4,
+            lit: '█',
+            unlit: ' ',
+        };
+        let mut crt = Crt::new(config).unwrap();
+
+        // a 4 pixel sprite at X covers X - 1 to X + 2
+        for _ in 0..8 {
+            crt.draw(&event(3));
+        }
+        crt.draw(&event(-2));
+        crt.draw(&event(-2));
+        assert_eq!(crt.drawn(), "  ████  \n█ \n");
+        assert_eq!(crt.print(), "  ████  \n█       \n");
+
+        crt.clear();
+        assert_eq!(crt.drawn(), "");
+        assert_eq!(crt.print(), "        \n        \n");
+
+        let err = Crt::new(CrtConfig { width: 0, ..config }).unwrap_err();
+        assert_eq!(err, "the screen has to be at least 1 pixel wide");
+        assert!(Crt::new(CrtConfig {
+            height: 0,
+            ..config
+        })
+        .is_ok());
+    }
+
+    #[test]
+    fn write_pgm_works() {
+        let config = CrtConfig {
+            width: 3,
+            height: 2,
+            ..CrtConfig::PUZZLE
+        };
+        let mut crt = Crt::new(config).unwrap();
+        crt.draw(&event(-1));
+        crt.draw(&event(5));
+
+        let mut out = vec![];
+        crt.write_pgm(2, &mut out).unwrap();
+        let (header, pixels) = out.split_at(11);
+        assert_eq!(header, b"P5\n6 4\n255\n");
+        assert_eq!(
+            pixels,
+            [
+                [255, 255, 0, 0, 48, 48],
+                [255, 255, 0, 0, 48, 48],
+                [48; 6],
+                [48; 6]
+            ]
+            .concat()
+        );
+    }
+
+    #[test]
+    fn animate_works() {
+        let program = parse(DEMO).unwrap();
+        let mut frames = vec![];
+
+        let crt = animate::<(), _>(Crt::default(), Cpu::new(&program).events(), |event, crt| {
+            frames.push((event.cycle, crt.drawn()));
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(frames.len(), 240);
+        assert_eq!(frames[3], (4, "##..\n".to_owned()));
+        assert_eq!(frames[239].1, crt.print());
+    }
+}
@@ -0,0 +1,323 @@
+use std::fmt::{self, Write};
+
+use aoc_common::{parse_number, ParseError, Result};
+
+use crate::{Cpu, Crt, CycleEvent, Instruction};
+
+/// How many cycles `continue` runs at most, so that a program looping forever gives control back.
+pub const CONTINUE_LIMIT: usize = 1_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Breakpoint {
+    /// Stop before the cycle with this number runs
+    Cycle(usize),
+    /// Stop before the instruction at this index starts
+    Pc(usize),
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Breakpoint::Cycle(cycle) => write!(f, "cycle {}", cycle),
+            Breakpoint::Pc(pc) => write!(f, "pc {}", pc),
+        }
+    }
+}
+
+/// A line typed at the debugger prompt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebugCommand {
+    /// Run this many cycles
+    Step(usize),
+    /// Run until a breakpoint, a watched register changes or the program stops
+    Continue,
+    Break(Breakpoint),
+    WatchX,
+    Print,
+    /// Start the program over, keeping breakpoints and watches
+    Reset,
+    Quit,
+}
+
+impl DebugCommand {
+    pub fn parse(line: &str) -> Result<Self> {
+        use DebugCommand::*;
+
+        let mut words = line.split_whitespace();
+        let name = words
+            .next()
+            .ok_or_else(|| ParseError::new("expected a command").with_text(line))?;
+        let args: Vec<_> = words.collect();
+        let number = |arg: &str| parse_number(arg).map_err(|err| err.locate(line, arg));
+
+        let command = match (name, args.as_slice()) {
+            ("step" | "s", []) => Step(1),
+            ("step" | "s", [count]) => Step(number(count)?),
+            ("continue" | "c", []) => Continue,
+            ("break" | "b", ["cycle", cycle]) => Break(Breakpoint::Cycle(number(cycle)?)),
+            ("break" | "b", ["pc", pc]) => Break(Breakpoint::Pc(number(pc)?)),
+            ("watch", ["x"]) => WatchX,
+            ("print" | "p", []) => Print,
+            ("reset", []) => Reset,
+            ("quit" | "q", []) => Quit,
+            _ => {
+                let usage = match name {
+                    "step" | "s" => "step [<count>]",
+                    "continue" | "c" => "continue",
+                    "break" | "b" => "break <cycle|pc> <n>",
+                    "watch" => "watch x",
+                    "print" | "p" => "print",
+                    "reset" => "reset",
+                    "quit" | "q" => "quit",
+                    _ => return Err(ParseError::new("unknown command").locate(line, name)),
+                };
+                return Err(ParseError::new(format!("expected `{}`", usage)).with_text(line));
+            }
+        };
+
+        Ok(command)
+    }
+}
+
+/// The CPU and the CRT it draws on, run a command at a time.
+#[derive(Debug)]
+pub struct Debugger {
+    cpu: Cpu,
+    crt: Crt,
+    breakpoints: Vec<Breakpoint>,
+    watch_x: bool,
+}
+
+impl Debugger {
+    pub fn new(program: &[Instruction]) -> Self {
+        Self::with_crt(program, Crt::default())
+    }
+
+    /// Draw on `crt` instead of the puzzle's screen.
+    pub fn with_crt(program: &[Instruction], crt: Crt) -> Self {
+        Self {
+            cpu: Cpu::new(program),
+            crt,
+            breakpoints: vec![],
+            watch_x: false,
+        }
+    }
+
+    pub fn cpu(&self) -> &Cpu {
+        &self.cpu
+    }
+
+    /// Run `command` and describe what happened.
+    pub fn execute(&mut self, command: DebugCommand) -> String {
+        match command {
+            DebugCommand::Step(count) => {
+                let ran = (0..count).take_while(|_| self.step().is_some()).count();
+                match ran < count {
+                    true => format!("program finished\n{}", self.state()),
+                    false => self.state(),
+                }
+            }
+            DebugCommand::Continue => format!("{}\n{}", self.resume(), self.state()),
+            DebugCommand::Break(breakpoint) => {
+                if !self.breakpoints.contains(&breakpoint) {
+                    self.breakpoints.push(breakpoint);
+                }
+                format!("breakpoint at {}\n", breakpoint)
+            }
+            DebugCommand::WatchX => {
+                self.watch_x = true;
+                "watching x\n".to_owned()
+            }
+            DebugCommand::Print => self.state(),
+            DebugCommand::Reset => {
+                self.cpu.reset();
+                self.crt.clear();
+                self.state()
+            }
+            DebugCommand::Quit => String::new(),
+        }
+    }
+
+    /// Run the commands of `script`, one per line, and return everything they printed after an
+    /// echo of the command.
+    ///
+    /// Blank lines and lines starting with `#` are skipped, and `quit` ends the script early.
+    pub fn run_script(&mut self, script: &str) -> Result<String> {
+        let mut res = String::new();
+
+        for (i, line) in script.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let command = DebugCommand::parse(line).map_err(|err| err.on_line(i + 1))?;
+            write!(res, "(dbg) {}\n{}", line, self.execute(command)).unwrap();
+            if command == DebugCommand::Quit {
+                break;
+            }
+        }
+
+        Ok(res)
+    }
+
+    fn step(&mut self) -> Option<CycleEvent> {
+        let event = self.cpu.step()?;
+        self.crt.draw(&event);
+
+        Some(event)
+    }
+
+    /// Run until something worth stopping for, and say what it was.
+    fn resume(&mut self) -> String {
+        for _ in 0..CONTINUE_LIMIT {
+            let event = match self.step() {
+                Some(event) => event,
+                None => return "program finished".to_owned(),
+            };
+
+            if self.watch_x && event.x_during != event.x_after {
+                return format!(
+                    "x changed from {} to {} during cycle {}",
+                    event.x_during, event.x_after, event.cycle
+                );
+            }
+            if let Some(breakpoint) = self.breakpoints.iter().find(|bp| self.is_at(bp)) {
+                return format!("stopped at {}", breakpoint);
+            }
+        }
+
+        format!("still running after {} cycles", CONTINUE_LIMIT)
+    }
+
+    fn is_at(&self, breakpoint: &Breakpoint) -> bool {
+        match *breakpoint {
+            Breakpoint::Cycle(cycle) => self.cpu.cycle() + 1 == cycle,
+            Breakpoint::Pc(pc) => {
+                !self.cpu.is_halted() && self.cpu.pc() == pc && self.cpu.elapsed() == 0
+            }
+        }
+    }
+
+    /// The cycles run, X, the instruction running next and the CRT so far.
+    fn state(&self) -> String {
+        let cpu = &self.cpu;
+        let mut res = format!("cycle {}, x = {}\n", cpu.cycle(), cpu.x());
+
+        match cpu.program().get(cpu.pc()) {
+            Some(instruction) => writeln!(
+                res,
+                "pc {}: {} ({} of {} cycles done)",
+                cpu.pc(),
+                instruction,
+                cpu.elapsed(),
+                instruction.cycles()
+            )
+            .unwrap(),
+            None => res.push_str("halted\n"),
+        }
+        res.push_str(&self.crt.drawn());
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    const DEMO: &str = include_str!("../data/demo.txt");
+
+    #[test]
+    fn debug_command_parse_works() {
+        use DebugCommand::*;
+
+        assert_eq!(DebugCommand::parse("step"), Ok(Step(1)));
+        assert_eq!(DebugCommand::parse("s 20"), Ok(Step(20)));
+        assert_eq!(
+            DebugCommand::parse("break pc 3"),
+            Ok(Break(Breakpoint::Pc(3)))
+        );
+        assert_eq!(
+            DebugCommand::parse("b cycle 20"),
+            Ok(Break(Breakpoint::Cycle(20)))
+        );
+        assert_eq!(DebugCommand::parse("watch x"), Ok(WatchX));
+
+        let err = DebugCommand::parse("run").unwrap_err();
+        assert_eq!(err.to_string(), "column 1: unknown command: \"run\"");
+        let err = DebugCommand::parse("break 3").unwrap_err();
+        assert_eq!(err.message(), "expected `break <cycle|pc> <n>`");
+        assert!(DebugCommand::parse("step x").is_err());
+        assert!(DebugCommand::parse("watch y").is_err());
+    }
+
+    #[test]
+    fn run_script_works() {
+        let program = parse("noop\naddx 3\naddx -5").unwrap();
+        let mut debugger = Debugger::new(&program);
+
+        let script =
+            "# stop once the first addx is done\nwatch x\ncontinue\n\nbreak pc 2\nstep\nc\nc\n";
+        assert_eq!(
+            debugger.run_script(script).unwrap(),
+            "(dbg) watch x\n\
+             watching x\n\
+             (dbg) continue\n\
+             x changed from 1 to 4 during cycle 3\n\
+             cycle 3, x = 4\n\
+             pc 2: addx -5 (0 of 2 cycles done)\n\
+             ###\n\
+             (dbg) break pc 2\n\
+             breakpoint at pc 2\n\
+             (dbg) step\n\
+             cycle 4, x = 4\n\
+             pc 2: addx -5 (1 of 2 cycles done)\n\
+             ####\n\
+             (dbg) c\n\
+             x changed from 4 to -1 during cycle 5\n\
+             cycle 5, x = -1\n\
+             halted\n\
+             #####\n\
+             (dbg) c\n\
+             program finished\n\
+             cycle 5, x = -1\n\
+             halted\n\
+             #####\n"
+        );
+
+        let err = debugger.run_script("print\n\nstep 1 2").unwrap_err();
+        assert_eq!(err.line(), Some(3));
+    }
+
+    #[test]
+    fn breakpoints_work() {
+        let mut debugger = Debugger::new(&parse(DEMO).unwrap());
+
+        let out = debugger
+            .run_script("b cycle 20\nc\nreset\nb pc 3\nc")
+            .unwrap();
+        assert!(out.contains("stopped at cycle 20\ncycle 19, x = 21\n"));
+        assert!(
+            out.contains("(dbg) reset\ncycle 0, x = 1\npc 0: addx 15 (0 of 2 cycles done)\n(dbg)")
+        );
+        assert!(out.ends_with(
+            "stopped at pc 3\ncycle 6, x = 11\npc 3: addx -3 (0 of 2 cycles done)\n##..##\n"
+        ));
+
+        debugger.execute(DebugCommand::Step(1000));
+        assert!(debugger.cpu().is_halted());
+        assert!(debugger
+            .execute(DebugCommand::Print)
+            .ends_with("#######.......#######.......#######.....\n"));
+    }
+
+    #[test]
+    fn continue_gives_up_on_endless_loops() {
+        let mut debugger = Debugger::new(&parse("noop\njmp -1").unwrap());
+
+        let out = debugger.execute(DebugCommand::Continue);
+        assert!(out.starts_with(&format!("still running after {} cycles\n", CONTINUE_LIMIT)));
+    }
+}
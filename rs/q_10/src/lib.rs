@@ -0,0 +1,133 @@
+mod cpu;
+mod crt;
+mod debugger;
+mod ocr;
+
+use aoc_common::{parse_lines, Answer, Result, Solution};
+
+pub use cpu::{Cpu, CycleEvent, Instruction, Opcode, Registers, OPCODES};
+pub use crt::{animate, Crt, CrtConfig};
+pub use debugger::{Breakpoint, DebugCommand, Debugger, CONTINUE_LIMIT};
+pub use ocr::{read_letters, UnknownGlyph, GLYPH_HEIGHT, GLYPH_WIDTH};
+
+#[derive(Debug)]
+pub struct Program {
+    instructions: Vec<Instruction>,
+}
+
+impl Program {
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+
+    /// `crt` once the program drew all of it.
+    pub fn screen(&self, crt: Crt) -> Crt {
+        run_crt(&self.instructions, crt)
+    }
+
+    /// The letters drawn on the CRT.
+    pub fn letters(&self) -> std::result::Result<String, UnknownGlyph> {
+        part2(&self.instructions)
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Instruction>> {
+    parse_lines(input, Instruction::parse)
+}
+
+/// The sum of the signal strengths during every 40th cycle from the 20th to the 220th, or `None`
+/// if the program stops before that.
+fn part1(instructions: &[Instruction]) -> Option<i64> {
+    let events: Vec<_> = Cpu::new(instructions).events().take(220).collect();
+    if events.len() < 220 {
+        return None;
+    }
+
+    let res = events
+        .iter()
+        .skip(19)
+        .step_by(40)
+        .map(|event| event.cycle as i64 * event.x_during as i64)
+        .sum();
+
+    Some(res)
+}
+
+fn run_crt(instructions: &[Instruction], mut crt: Crt) -> Crt {
+    // a program that loops forever still only draws one screen
+    for event in Cpu::new(instructions).events().take(crt.config().pixels()) {
+        crt.draw(&event);
+    }
+
+    crt
+}
+
+fn part2(instructions: &[Instruction]) -> std::result::Result<String, UnknownGlyph> {
+    read_letters(run_crt(instructions, Crt::default()).screen())
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Program;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(Program {
+            instructions: parse(input)?,
+        })
+    }
+
+    fn part1(program: &Self::Input) -> Answer {
+        part1(&program.instructions).map_or(Answer::NoSolution, Answer::from)
+    }
+
+    /// The letters on the screen, or the picture itself when it doesn't show letters like the
+    /// demo's.
+    fn part2(program: &Self::Input) -> Answer {
+        let crt = program.screen(Crt::default());
+        match read_letters(crt.screen()) {
+            Ok(letters) => letters.into(),
+            Err(_) => crt.print().into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::read_to_string;
+
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let instructions = parse(&read_to_string("./data/demo.txt").unwrap()).unwrap();
+        let res = part1(&instructions);
+        assert_eq!(res, Some(13140));
+
+        // the strengths don't fit in X's type, and a program looping forever is fine
+        let instructions = parse("addx 2147483646\njmp 0").unwrap();
+        assert_eq!(part1(&instructions), Some(720 * i32::MAX as i64));
+        assert_eq!(part1(&parse("noop").unwrap()), None);
+    }
+
+    #[test]
+    fn part2_works() {
+        let instructions = parse(&read_to_string("./data/demo.txt").unwrap()).unwrap();
+        let res = run_crt(&instructions, Crt::default()).print();
+        let expected = "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....
+";
+        assert_eq!(res, expected);
+
+        let err = part2(&instructions).unwrap_err();
+        assert_eq!(err.index, 0);
+        assert_eq!(err.glyph, "##..\n###.\n####\n####\n####\n####\n");
+
+        let program = Day10::parse(&read_to_string("./data/demo.txt").unwrap()).unwrap();
+        assert_eq!(Day10::part2(&program), Answer::Text(expected.to_owned()));
+    }
+}
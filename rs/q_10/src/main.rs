@@ -1,255 +1,208 @@
-use std::{fmt::Write, fs::read_to_string, path::Path};
-
-const CRT_WIDTH: usize = 40;
-const CRT_HEIGHT: usize = 6;
-const CRT_LIT: char = '#';
-const CRT_UNLIT: char = '.';
-
-#[derive(Debug, PartialEq, Clone)]
-enum Command {
-    Noop,
-    AddX(i32),
+use std::{
+    env,
+    fs::{self, File},
+    io::{self, BufRead, BufWriter, Write},
+    path::Path,
+    process,
+};
+
+use aoc_common::{InputSource, Solution};
+use q_10::{animate, Cpu, Crt, CrtConfig, Day10, DebugCommand, Debugger, Program};
+
+/// What to do with the program instead of printing the answers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Mode {
+    /// Read debugger commands from the terminal
+    Debug,
+    /// Run the debugger commands in a file
+    Script(String),
+    /// Export the final screen as a PGM image
+    Image(String),
+    /// Export the screen after every cycle as numbered PGM images in a directory
+    Frames(String),
 }
 
-use Command::*;
-
-impl Command {
-    pub fn from_str(input: &str) -> Self {
-        if input == "noop" {
-            Noop
-        } else {
-            let value = input.split(' ').nth(1).unwrap().parse::<i32>().unwrap();
-            AddX(value)
-        }
-    }
+#[derive(Debug)]
+struct Options {
+    mode: Option<Mode>,
+    crt: CrtConfig,
+    /// The side of a CRT pixel in the exported images
+    scale: usize,
 }
 
-struct Cpu {
-    tick: usize,
-    x: i32,
-    commands: Vec<Command>,
-    pc: usize,
-    last_started: usize,
+fn parse_value<T>(arg: &str, value: Option<String>) -> Result<T, String>
+where
+    T: std::str::FromStr,
+{
+    let value = value.ok_or_else(|| format!("Missing value for {}", arg))?;
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value for {}: {}", arg, value))
 }
 
-impl Cpu {
-    pub fn new(commands: &[Command]) -> Self {
-        Self {
-            tick: 0,
-            x: 1,
-            commands: commands.to_vec(),
-            pc: 0,
-            last_started: 0,
-        }
-    }
-
-    pub fn next_tick(&mut self) {
-        self.tick += 1;
-    }
-
-    pub fn exec(&mut self) -> Option<()> {
-        let cmd = self.commands.get(self.pc)?;
-
-        match cmd {
-            Noop => {
-                self.pc += 1;
-                self.last_started += 1;
-            }
-            AddX(value) => {
-                if self.tick - self.last_started == 2 {
-                    self.last_started = self.tick;
-                    self.pc += 1;
-                    self.x += value;
+fn parse_options(args: impl IntoIterator<Item = String>) -> Result<(Options, Vec<String>), String> {
+    let mut options = Options {
+        mode: None,
+        crt: CrtConfig::PUZZLE,
+        scale: 8,
+    };
+    let mut rest = vec![];
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        let mode = match arg.as_str() {
+            "--debug" => Mode::Debug,
+            "--script" => Mode::Script(parse_value(&arg, args.next())?),
+            "--image" => Mode::Image(parse_value(&arg, args.next())?),
+            "--frames" => Mode::Frames(parse_value(&arg, args.next())?),
+            "--width" | "--height" | "--sprite-width" | "--scale" => {
+                let value = parse_value(&arg, args.next())?;
+                if value == 0 {
+                    return Err(format!("{} has to be at least 1", arg));
+                }
+                match arg.as_str() {
+                    "--width" => options.crt.width = value,
+                    "--height" => options.crt.height = value,
+                    "--sprite-width" => options.crt.sprite_width = value,
+                    _ => options.scale = value,
                 }
+                continue;
+            }
+            "--lit" => {
+                options.crt.lit = parse_value(&arg, args.next())?;
+                continue;
+            }
+            "--unlit" => {
+                options.crt.unlit = parse_value(&arg, args.next())?;
+                continue;
+            }
+            _ => {
+                rest.push(arg);
+                continue;
             }
         };
-
-        Some(())
+        if options.mode.replace(mode).is_some() {
+            return Err(
+                "Only one of --debug, --script, --image and --frames can be given".to_owned(),
+            );
+        }
     }
 
-    pub fn signal_str(&self) -> i32 {
-        self.tick as i32 * self.x
-    }
+    Ok((options, rest))
 }
 
-struct Crt {
-    buffer: Vec<String>,
+fn export_image(program: &Program, crt: Crt, options: &Options, path: &str) -> io::Result<()> {
+    let out = BufWriter::new(File::create(path)?);
+    program.screen(crt).write_pgm(options.scale, out)
 }
 
-impl Crt {
-    pub fn new() -> Self {
-        Self {
-            buffer: Vec::with_capacity(CRT_HEIGHT),
-        }
-    }
-
-    pub fn add_pixel(&mut self, is_lit: bool) {
-        if self.buffer.is_empty() {
-            self.new_row();
-        }
+/// Write a frame per cycle, and return how many there were.
+fn export_frames(program: &Program, crt: Crt, options: &Options, dir: &str) -> io::Result<usize> {
+    fs::create_dir_all(dir)?;
+    let digits = options.crt.pixels().to_string().len();
+    let mut frames = 0;
+
+    animate(
+        crt,
+        Cpu::new(program.instructions()).events(),
+        |event, crt| {
+            frames += 1;
+            let path = Path::new(dir).join(format!("frame_{:0digits$}.pgm", event.cycle));
+            crt.write_pgm(options.scale, BufWriter::new(File::create(path)?))
+        },
+    )?;
+
+    Ok(frames)
+}
 
-        let mut last = self.buffer.last_mut().unwrap();
-        if last.len() == CRT_WIDTH {
-            self.new_row();
-            last = self.buffer.last_mut().unwrap();
-        }
+fn repl(debugger: &mut Debugger) -> io::Result<()> {
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
 
-        match is_lit {
-            true => last.push(CRT_LIT),
-            false => last.push(CRT_UNLIT),
+    loop {
+        print!("(dbg) ");
+        io::stdout().flush()?;
+        let line = match lines.next() {
+            Some(line) => line?,
+            None => return Ok(()),
         };
-    }
-
-    fn new_row(&mut self) {
-        self.buffer.push(String::with_capacity(CRT_WIDTH));
-    }
-
-    pub fn get_current_idx(&self) -> usize {
-        if self.buffer.is_empty() {
-            return 0;
+        if line.trim().is_empty() {
+            continue;
         }
 
-        let row = self.buffer.last().unwrap();
-        if row.len() == CRT_WIDTH {
-            0
-        } else {
-            row.len()
-        }
-    }
-
-    pub fn print(&self) -> String {
-        let mut res = String::new();
-
-        for line in self.buffer.iter() {
-            writeln!(res, "{}", line).unwrap();
+        match DebugCommand::parse(&line) {
+            Ok(DebugCommand::Quit) => return Ok(()),
+            Ok(command) => print!("{}", debugger.execute(command)),
+            Err(err) => println!("{}", err),
         }
-
-        res
     }
 }
 
-fn parse_file(path: &str) -> Vec<Command> {
-    read_to_string(Path::new(path))
-        .unwrap()
-        .lines()
-        .map(Command::from_str)
-        .collect()
+fn fail(err: impl std::fmt::Display) -> ! {
+    eprintln!("{}", err);
+    process::exit(1);
 }
 
-fn part1(commands: &[Command]) -> i32 {
-    let mut cpu = Cpu::new(commands);
-    let mut next_signal_check = 20;
-    let mut total_str = 0;
-
-    while cpu.tick < 220 {
-        cpu.next_tick();
-
-        if cpu.tick == next_signal_check {
-            next_signal_check += 40;
-            total_str += cpu.signal_str();
+fn main() {
+    let res = parse_options(env::args().skip(1)).and_then(|(options, rest)| {
+        let source = InputSource::from_args(rest)?;
+        if options.mode == Some(Mode::Debug) && source == InputSource::Stdin {
+            return Err(
+                "--debug reads commands from stdin, so the program can't come from it".to_owned(),
+            );
         }
-
-        cpu.exec().unwrap();
-    }
-
-    total_str
-}
-
-fn part2(commands: &[Command]) -> String {
-    let mut cpu = Cpu::new(commands);
-    let mut crt = Crt::new();
-
-    loop {
-        cpu.next_tick();
-
-        let draw_idx = crt.get_current_idx() as i32;
-        let is_lit = draw_idx >= cpu.x - 1 && draw_idx <= cpu.x + 1;
-
-        if cpu.exec().is_none() {
-            break;
+        let input = source.read(Path::new(env!("CARGO_MANIFEST_DIR")))?;
+        let program = Day10::parse(&input).map_err(|err| err.to_string())?;
+        let crt = Crt::new(options.crt)?;
+        Ok((options, program, crt))
+    });
+    let (options, program, crt) = res.unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        eprintln!(
+            "Usage: [--debug | --script <path> | --image <path.pgm> | --frames <dir>] \
+             [--width <n>] [--height <n>] [--sprite-width <n>] [--lit <char>] [--unlit <char>] \
+             [--scale <n>] [--demo | - | <path>]"
+        );
+        process::exit(1);
+    });
+
+    match &options.mode {
+        Some(Mode::Debug) => {
+            let mut debugger = Debugger::with_crt(program.instructions(), crt);
+            repl(&mut debugger).unwrap_or_else(|err| fail(err));
+        }
+        Some(Mode::Script(path)) => {
+            let mut debugger = Debugger::with_crt(program.instructions(), crt);
+            let script = fs::read_to_string(path)
+                .unwrap_or_else(|err| fail(format!("Couldn't read {}: {}", path, err)));
+            match debugger.run_script(&script) {
+                Ok(output) => print!("{}", output),
+                Err(err) => fail(format!("{}: {}", path, err)),
+            }
+        }
+        Some(Mode::Image(path)) => export_image(&program, crt, &options, path)
+            .unwrap_or_else(|err| fail(format!("Couldn't write {}: {}", path, err))),
+        Some(Mode::Frames(dir)) => {
+            let frames = export_frames(&program, crt, &options, dir)
+                .unwrap_or_else(|err| fail(format!("Couldn't write to {}: {}", dir, err)));
+            println!("wrote {} frames to {}", frames, dir);
+        }
+        None if options.crt != CrtConfig::PUZZLE => {
+            print!("{}", program.screen(crt).print());
+        }
+        None => {
+            let part1_res = Day10::part1(&program);
+            let part2_res = Day10::part2(&program);
+            println!("part 1: {}", part1_res);
+            println!("{}", "-".repeat(20));
+            match program.letters() {
+                Ok(_) => println!("part 2: {}", part2_res),
+                Err(err) => {
+                    eprintln!("{}", err);
+                    println!("part 2:");
+                    print!("{}", part2_res);
+                }
+            }
         }
-
-        crt.add_pixel(is_lit);
-    }
-
-    crt.print()
-}
-
-fn main() {
-    let commands = parse_file("./data/input.txt");
-    let part1_res = part1(&commands);
-    let part2_res = part2(&commands);
-    println!("part 1: {:#?}", part1_res);
-    println!("{}", "-".repeat(20));
-    println!("part 2:");
-    print!("{}", part2_res);
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn next_tick_works() {
-        let commands = vec![Noop, AddX(3), AddX(-5)];
-        let mut cpu = Cpu::new(&commands);
-
-        cpu.next_tick();
-        cpu.exec();
-        assert_eq!(cpu.tick, 1);
-        assert_eq!(cpu.x, 1);
-        assert_eq!(cpu.pc, 1);
-
-        cpu.next_tick();
-        cpu.exec();
-        assert_eq!(cpu.tick, 2);
-        assert_eq!(cpu.x, 1);
-        assert_eq!(cpu.pc, 1);
-
-        cpu.next_tick();
-        cpu.exec();
-        assert_eq!(cpu.tick, 3);
-        assert_eq!(cpu.x, 4);
-        assert_eq!(cpu.pc, 2);
-
-        cpu.next_tick();
-        cpu.exec();
-        assert_eq!(cpu.tick, 4);
-        assert_eq!(cpu.x, 4);
-        assert_eq!(cpu.pc, 2);
-
-        cpu.next_tick();
-        cpu.exec();
-        assert_eq!(cpu.tick, 5);
-        assert_eq!(cpu.x, -1);
-        assert_eq!(cpu.pc, 3);
-    }
-
-    #[test]
-    fn command_from_str_works() {
-        assert_eq!(Command::from_str("noop"), Noop);
-        assert_eq!(Command::from_str("addx 15"), AddX(15));
-        assert_eq!(Command::from_str("addx -11"), AddX(-11));
-    }
-
-    #[test]
-    fn part1_works() {
-        let commands = parse_file("./data/demo.txt");
-        let res = part1(&commands);
-        assert_eq!(res, 13140);
-    }
-
-    #[test]
-    fn part2_works() {
-        let commands = parse_file("./data/demo.txt");
-        let res = part2(&commands);
-        let expected = "##..##..##..##..##..##..##..##..##..##..
-###...###...###...###...###...###...###.
-####....####....####....####....####....
-#####.....#####.....#####.....#####.....
-######......######......######......####
-#######.......#######.......#######.....
-";
-        assert_eq!(res, expected);
     }
 }
@@ -0,0 +1,117 @@
+use std::{error, fmt};
+
+use aoc_common::Grid;
+
+/// Every glyph takes this many columns, the last one being blank.
+pub const GLYPH_WIDTH: usize = 5;
+pub const GLYPH_HEIGHT: usize = 6;
+
+/// The letters of the Advent of Code font, drawn over their 4 lit columns.
+const FONT: [(char, &str); 17] = [
+    ('A', ".##.#..##..######..##..#"),
+    ('B', "###.#..####.#..##..####."),
+    ('C', ".##.#..##...#...#..#.##."),
+    ('E', "#####...###.#...#...####"),
+    ('F', "#####...###.#...#...#..."),
+    ('G', ".##.#..##...#.###..#.###"),
+    ('H', "#..##..######..##..##..#"),
+    ('I', ".###..#...#...#...#..###"),
+    ('J', "..##...#...#...##..#.##."),
+    ('K', "#..##.#.##..#.#.#.#.#..#"),
+    ('L', "#...#...#...#...#...####"),
+    ('O', ".##.#..##..##..##..#.##."),
+    ('P', "###.#..##..####.#...#..."),
+    ('R', "###.#..##..####.#.#.#..#"),
+    ('S', ".####...#....##....####."),
+    ('U', "#..##..##..##..##..#.##."),
+    ('Z', "####...#..#..#..#...####"),
+];
+
+/// A glyph that isn't a letter of the font.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownGlyph {
+    /// Counting glyphs from the left, starting at 0
+    pub index: usize,
+    /// The glyph drawn with `#` and `.`, a row per line
+    pub glyph: String,
+}
+
+impl fmt::Display for UnknownGlyph {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unknown glyph {}:\n{}",
+            self.index,
+            self.glyph.trim_end()
+        )
+    }
+}
+
+impl error::Error for UnknownGlyph {}
+
+/// Read the letters drawn on `screen`, a glyph every [`GLYPH_WIDTH`] columns.
+pub fn read_letters(screen: &Grid<bool>) -> Result<String, UnknownGlyph> {
+    (0..screen.width().div_ceil(GLYPH_WIDTH))
+        .map(|index| {
+            let glyph = glyph(screen, index * GLYPH_WIDTH);
+            let pattern: String = glyph.lines().collect();
+            FONT.iter()
+                .find(|(_, letter)| *letter == pattern)
+                .map(|(letter, _)| *letter)
+                .ok_or(UnknownGlyph { index, glyph })
+        })
+        .collect()
+}
+
+/// The 4 columns of the glyph starting at `column`, with the ones past the screen unlit.
+fn glyph(screen: &Grid<bool>, column: usize) -> String {
+    let mut res = String::new();
+    for row in 0..GLYPH_HEIGHT {
+        for column in column..column + GLYPH_WIDTH - 1 {
+            let is_lit = screen.get((row, column)).copied().unwrap_or(false);
+            res.push(if is_lit { '#' } else { '.' });
+        }
+        res.push('\n');
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Draw `text` in the font, the way the CRT would.
+    fn draw(text: &str) -> Grid<bool> {
+        let mut screen = Grid::new(text.len() * GLYPH_WIDTH, GLYPH_HEIGHT, false);
+        for (i, letter) in text.chars().enumerate() {
+            let (_, pattern) = FONT.iter().find(|(c, _)| *c == letter).unwrap();
+            for (j, pixel) in pattern.chars().enumerate() {
+                screen[(j / 4, i * GLYPH_WIDTH + j % 4)] = pixel == '#';
+            }
+        }
+
+        screen
+    }
+
+    #[test]
+    fn read_letters_works() {
+        let alphabet: String = FONT.iter().map(|(letter, _)| letter).collect();
+        assert_eq!(read_letters(&draw(&alphabet)), Ok(alphabet));
+        assert_eq!(read_letters(&draw("EHZFZHCZ")).unwrap(), "EHZFZHCZ");
+    }
+
+    #[test]
+    fn unknown_glyph_works() {
+        let mut screen = draw("RLZ");
+        screen[(0, 6)] = true;
+
+        let err = read_letters(&screen).unwrap_err();
+        assert_eq!(err.index, 1);
+        assert_eq!(
+            err.to_string(),
+            "unknown glyph 1:\n##..\n#...\n#...\n#...\n#...\n####"
+        );
+        assert_eq!(read_letters(&Grid::new(40, 6, false)).unwrap_err().index, 0);
+    }
+}
@@ -0,0 +1,292 @@
+use std::collections::VecDeque;
+
+use aoc_common::{parse_blocks, parse_number, Answer, ParseError, Result, Solution};
+
+#[derive(Debug, Clone)]
+struct Monkey {
+    items: VecDeque<u128>,
+    operation: Operation,
+    test: Test,
+    inspected: u64,
+}
+
+impl Monkey {
+    pub fn from_str(input: &str) -> Result<Self> {
+        let lines = input.lines().collect::<Vec<&str>>();
+        if lines.len() != 6 {
+            return Err(ParseError::new(format!(
+                "expected 6 lines of notes per monkey, found {}",
+                lines.len()
+            )));
+        }
+
+        Ok(Self {
+            items: Self::parse_items(lines[1]).map_err(|err| err.on_line(2))?,
+            operation: Operation::from_str(lines[2]).map_err(|err| err.on_line(3))?,
+            test: Test::from_lines(&lines[3..=5]).map_err(|err| err.on_line(4))?,
+            inspected: 0,
+        })
+    }
+
+    pub fn throw_all<F>(&mut self, modify: F) -> Vec<(usize, u128)>
+    where
+        F: Fn(u128) -> u128,
+    {
+        let mut res = vec![];
+
+        while let Some(item) = self.get_next() {
+            let mut _item = self.operation.execute(item);
+            _item = modify(_item);
+            res.push((self.test.execute(&_item), _item));
+            self.inspected += 1;
+        }
+
+        res
+    }
+
+    pub fn receive(&mut self, item: u128) {
+        self.items.push_back(item);
+    }
+
+    fn get_next(&mut self) -> Option<u128> {
+        self.items.pop_front()
+    }
+
+    fn parse_items(line: &str) -> Result<VecDeque<u128>> {
+        field(line, "Starting items: ")?
+            .split(", ")
+            .map(|num| parse_number(num).map_err(|err| err.locate(line, num)))
+            .collect()
+    }
+}
+
+/// Strip `label` off one line of a monkey's notes, ignoring the indentation.
+fn field<'a>(line: &'a str, label: &str) -> Result<&'a str> {
+    line.trim_start()
+        .strip_prefix(label)
+        .ok_or_else(|| ParseError::new(format!("expected `{}`", label.trim_end())).with_text(line))
+}
+
+#[derive(Debug, Clone)]
+struct Operation {
+    // The left operand is always `old`
+    right: Operand,
+    operator: Operator,
+}
+
+impl Operation {
+    pub fn from_str(line: &str) -> Result<Self> {
+        let expression = field(line, "Operation: new = old ")?;
+        let (sign, right) = expression.split_once(' ').ok_or_else(|| {
+            ParseError::new("expected `<operator> <operand>`").locate(line, expression)
+        })?;
+
+        let operator = match sign {
+            "+" => Operator::Add,
+            "*" => Operator::Multiply,
+            _ => return Err(ParseError::new("invalid operator").locate(line, sign)),
+        };
+
+        let right = match right {
+            "old" => Operand::Old,
+            val => Operand::Literal(parse_number(val).map_err(|err| err.locate(line, val))?),
+        };
+
+        Ok(Self { operator, right })
+    }
+
+    pub fn execute(&self, old: u128) -> u128 {
+        let right = match self.right {
+            Operand::Old => old,
+            Operand::Literal(val) => val,
+        };
+
+        match self.operator {
+            Operator::Add => old + right,
+            Operator::Multiply => old * right,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Operand {
+    Old,
+    Literal(u128),
+}
+
+#[derive(Debug, Clone)]
+enum Operator {
+    Add,
+    Multiply,
+}
+
+#[derive(Debug, Clone)]
+struct Test {
+    divident: u128,
+    passes: usize,
+    fails: usize,
+}
+
+impl Test {
+    pub fn from_lines(lines: &[&str]) -> Result<Self> {
+        let divident = field(lines[0], "Test: divisible by ")?;
+        let divident = match parse_number(divident) {
+            Ok(0) => {
+                Err(ParseError::new("can't test divisibility by 0").locate(lines[0], divident))
+            }
+            res => res.map_err(|err| err.locate(lines[0], divident)),
+        }?;
+
+        Ok(Self {
+            divident,
+            passes: Self::parse_throw(lines[1], "If true: throw to monkey ")
+                .map_err(|err| err.on_line(2))?,
+            fails: Self::parse_throw(lines[2], "If false: throw to monkey ")
+                .map_err(|err| err.on_line(3))?,
+        })
+    }
+
+    pub fn execute(&self, value: &u128) -> usize {
+        match value.is_multiple_of(self.divident) {
+            true => self.passes,
+            false => self.fails,
+        }
+    }
+
+    fn parse_throw(line: &str, label: &str) -> Result<usize> {
+        let target = field(line, label)?;
+        parse_number(target).map_err(|err| err.locate(line, target))
+    }
+}
+
+fn round<F>(monkeys: &mut [Monkey], modify: F)
+where
+    F: Fn(u128) -> u128,
+{
+    for i in 0..monkeys.len() {
+        let throws = monkeys[i].throw_all(&modify);
+
+        for throw in throws {
+            monkeys[throw.0].receive(throw.1);
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Troop {
+    monkeys: Vec<Monkey>,
+}
+
+fn parse_monkeys(input: &str) -> Result<Vec<Monkey>> {
+    let monkeys = parse_blocks(input, Monkey::from_str)?;
+    // the monkey business multiplies the two busiest monkeys
+    if monkeys.len() < 2 {
+        return Err(ParseError::new("expected at least two monkeys"));
+    }
+
+    for (i, monkey) in monkeys.iter().enumerate() {
+        for target in [monkey.test.passes, monkey.test.fails] {
+            if target >= monkeys.len() {
+                return Err(ParseError::new(format!(
+                    "monkey {} throws to monkey {}, but there are only {} monkeys",
+                    i,
+                    target,
+                    monkeys.len()
+                )));
+            }
+        }
+    }
+
+    Ok(monkeys)
+}
+
+fn part1_worry(worry: u128) -> u128 {
+    worry / 3
+}
+
+fn part1(monkeys: &mut [Monkey]) -> u64 {
+    for _ in 0..20 {
+        round(monkeys, part1_worry);
+    }
+
+    let mut inspected = monkeys
+        .iter()
+        .map(|monkey| monkey.inspected)
+        .collect::<Vec<_>>();
+
+    inspected.sort_by(|a, b| b.cmp(a));
+
+    inspected[0] * inspected[1]
+}
+
+fn part2(monkeys: &mut [Monkey]) -> u64 {
+    let modulo = monkeys
+        .iter()
+        .map(|monkey| monkey.test.divident)
+        .product::<u128>();
+
+    for _ in 0..10_000 {
+        round(monkeys, |item| item % modulo);
+    }
+
+    let mut inspected = monkeys
+        .iter()
+        .map(|monkey| monkey.inspected)
+        .collect::<Vec<_>>();
+
+    inspected.sort_by(|a, b| b.cmp(a));
+
+    inspected[0] * inspected[1]
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Troop;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(Troop {
+            monkeys: parse_monkeys(input)?,
+        })
+    }
+
+    fn part1(troop: &Self::Input) -> Answer {
+        part1(&mut troop.monkeys.clone()).into()
+    }
+
+    fn part2(troop: &Self::Input) -> Answer {
+        part2(&mut troop.monkeys.clone()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::read_to_string;
+
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let mut monkeys = parse_monkeys(&read_to_string("./data/demo.txt").unwrap()).unwrap();
+
+        let monkey_business = part1(&mut monkeys);
+        assert_eq!(monkey_business, 10605);
+    }
+
+    #[test]
+    fn part2_works() {
+        let mut monkeys = parse_monkeys(&read_to_string("./data/demo.txt").unwrap()).unwrap();
+
+        let monkey_business = part2(&mut monkeys);
+        assert_eq!(monkey_business, 2713310158);
+    }
+
+    #[test]
+    fn parse_monkeys_needs_two_monkeys() {
+        let demo = read_to_string("./data/demo.txt").unwrap();
+        let (first, _) = demo.split_once("\n\n").unwrap();
+
+        let err = parse_monkeys(first).unwrap_err();
+        assert_eq!(err.message(), "expected at least two monkeys");
+    }
+}
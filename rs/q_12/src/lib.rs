@@ -0,0 +1,171 @@
+use std::cmp::Ordering;
+
+use aoc_common::{Answer, Grid, ParseError, Position, Result, Solution};
+
+use crate::dijkstra::Graph;
+
+mod dijkstra;
+
+pub type Coord = Position;
+
+#[derive(Debug)]
+pub struct HeightMap {
+    pub heights: Grid<u8>,
+    pub start: Coord,
+    pub end: Coord,
+}
+
+fn char_to_height(ch: char) -> Option<u8> {
+    match ch {
+        'S' => Some(0),
+        'E' => Some(25),
+        'a'..='z' => Some(ch as u8 - b'a'),
+        _ => None,
+    }
+}
+
+fn parse_map(input: &str) -> Result<HeightMap> {
+    let mut start = None;
+    let mut end = None;
+
+    let heights = Grid::from_chars(input, |pos, ch| {
+        if ch == 'S' || ch == 'E' {
+            let marker = if ch == 'S' { &mut start } else { &mut end };
+            if marker.replace(pos).is_some() {
+                return Err(ParseError::new(format!(
+                    "the map has more than one `{}`",
+                    ch
+                )));
+            }
+        }
+
+        char_to_height(ch).ok_or_else(|| ParseError::new("invalid height"))
+    })?;
+
+    Ok(HeightMap {
+        heights,
+        start: start.ok_or_else(|| ParseError::new("the map has no start `S`"))?,
+        end: end.ok_or_else(|| ParseError::new("the map has no end `E`"))?,
+    })
+}
+
+/// The squares of the shortest path from `S` to `E`, both included.
+pub fn shortest_path(map: &HeightMap) -> Option<Vec<Coord>> {
+    Graph::new(map).shortest(&map.start).path_to(&map.end)
+}
+
+/// Draw `path` over the map like the puzzle does: every square shows the direction of the next
+/// step, the last one is marked `E` and squares off the path are left as `.`.
+pub fn render_path(map: &HeightMap, path: &[Coord]) -> String {
+    let mut grid = map.heights.map(|_| '.');
+
+    for step in path.windows(2) {
+        let (from, to) = (step[0], step[1]);
+        grid[from] = match (to.0.cmp(&from.0), to.1.cmp(&from.1)) {
+            (Ordering::Less, _) => '^',
+            (Ordering::Greater, _) => 'v',
+            (_, Ordering::Less) => '<',
+            _ => '>',
+        };
+    }
+    if let Some(last) = path.last() {
+        grid[*last] = 'E';
+    }
+
+    grid.to_string()
+}
+
+/// The fewest steps from `S` to `E`, or `None` if `E` can't be reached.
+fn part1(map: &HeightMap) -> Option<u64> {
+    let graph = Graph::new(map);
+    let res = graph.shortest(&map.start);
+    res.distance(&map.end)
+}
+
+/// The fewest steps from any lowest square to `E`, or `None` if none of them reaches it.
+fn part2(map: &HeightMap) -> Option<u64> {
+    // walking down from `E` reaches every square that could climb up to it, so a single search
+    // finds the closest of all the lowest squares
+    let graph = Graph::reversed(map);
+    let res = graph.shortest(&map.end);
+
+    map.heights
+        .iter()
+        .filter(|(_, height)| **height == 0)
+        .filter_map(|(pos, _)| res.distance(&pos))
+        .min()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = HeightMap;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_map(input)
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        part1(map).map_or(Answer::NoSolution, Answer::from)
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        part2(map).map_or(Answer::NoSolution, Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::read_to_string;
+
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let map = parse_map(&read_to_string("./data/demo.txt").unwrap()).unwrap();
+        assert_eq!(part1(&map), Some(31));
+    }
+
+    #[test]
+    fn part2_works() {
+        let map = parse_map(&read_to_string("./data/demo.txt").unwrap()).unwrap();
+        assert_eq!(part2(&map), Some(29));
+    }
+
+    #[test]
+    fn unreachable_end_works() {
+        // `E` is two steps above everything around it
+        let map = parse_map("Sab\nabE").unwrap();
+        assert_eq!(part1(&map), None);
+        assert_eq!(part2(&map), None);
+        assert_eq!(Day12::part1(&map), Answer::NoSolution);
+    }
+
+    #[test]
+    fn render_path_works() {
+        let map = parse_map(&read_to_string("./data/demo.txt").unwrap()).unwrap();
+        let path = shortest_path(&map).unwrap();
+        let expected = ">>vv<<<<
+..vvv<<^
+..vv>E^^
+..v>>>^^
+..>>>>>^";
+
+        assert_eq!(path.len(), 32);
+        assert_eq!(render_path(&map, &path), expected);
+    }
+
+    #[test]
+    fn shortest_from_works() {
+        // climbing from every lowest square at once agrees with walking down from `E`
+        let map = parse_map(&read_to_string("./data/demo.txt").unwrap()).unwrap();
+        let lowest = map
+            .heights
+            .iter()
+            .filter(|(_, height)| **height == 0)
+            .map(|(pos, _)| pos);
+        let res = Graph::new(&map).shortest_from(lowest);
+
+        assert_eq!(res.distance(&map.end), Some(29));
+    }
+}
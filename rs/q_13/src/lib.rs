@@ -0,0 +1,182 @@
+use std::cmp::Ordering;
+
+use aoc_common::{parse_blocks, parse_lines, Answer, ParseError, Result, Solution};
+use serde_json::{from_str, Number, Value};
+
+fn is_packet(value: &Value) -> bool {
+    match value {
+        Value::Number(num) => num.is_u64(),
+        Value::Array(list) => list.iter().all(is_packet),
+        _ => false,
+    }
+}
+
+fn parse_packet(line: &str) -> Result<Value> {
+    let packet = from_str::<Value>(line).map_err(|err| {
+        ParseError::new("invalid packet")
+            .with_text(line)
+            .at_column(err.column())
+    })?;
+
+    if !is_packet(&packet) {
+        return Err(ParseError::new("packets can only contain lists and integers").with_text(line));
+    }
+
+    Ok(packet)
+}
+
+fn parse(input: &str) -> Result<Vec<Vec<Value>>> {
+    parse_blocks(input, |lines| {
+        let pair = parse_lines(lines, parse_packet)?;
+        if pair.len() != 2 {
+            return Err(ParseError::new(format!(
+                "expected a pair of packets, found {}",
+                pair.len()
+            )));
+        }
+
+        Ok(pair)
+    })
+}
+
+fn compare(a: &Value, b: &Value) -> Ordering {
+    if let Value::Number(a) = a {
+        if let Value::Number(b) = b {
+            return compare_nums(a, b);
+        }
+    }
+
+    let a_list = match a {
+        Value::Number(_) => vec![a],
+        Value::Array(list) => list.iter().collect::<Vec<&Value>>(),
+        _ => panic!("Invalid type"),
+    };
+
+    let b_list = match b {
+        Value::Number(_) => vec![b],
+        Value::Array(list) => list.iter().collect::<Vec<&Value>>(),
+        _ => panic!("Invalid type"),
+    };
+
+    compare_lists(&a_list, &b_list)
+}
+
+fn compare_lists(a: &[&Value], b: &[&Value]) -> Ordering {
+    let max_len = a.len().max(b.len());
+
+    for i in 0..max_len {
+        let a_el = a.get(i);
+        let b_el = b.get(i);
+
+        if a_el.is_none() {
+            return Ordering::Less;
+        }
+
+        if b_el.is_none() {
+            return Ordering::Greater;
+        }
+
+        let a_el = a_el.unwrap();
+        let b_el = b_el.unwrap();
+        let cmp = compare(a_el, b_el);
+        if cmp != Ordering::Equal {
+            return cmp;
+        }
+    }
+
+    Ordering::Equal
+}
+
+fn compare_nums(a: &Number, b: &Number) -> Ordering {
+    let a_num = a.as_u64().unwrap();
+    let b_num = b.as_u64().unwrap();
+
+    a_num.cmp(&b_num)
+}
+
+fn part1(pairs: &[Vec<Value>]) -> usize {
+    let mut sum = 0;
+
+    for (i, pair) in pairs.iter().enumerate() {
+        let cmp = compare(&pair[0], &pair[1]);
+        if cmp == Ordering::Equal || cmp == Ordering::Less {
+            sum += i + 1;
+        }
+    }
+
+    sum
+}
+
+fn part2(pairs: &[Vec<Value>]) -> usize {
+    let mut sorted = vec![];
+
+    for pair in pairs.iter() {
+        sorted.push(pair[0].clone());
+        sorted.push(pair[1].clone());
+    }
+
+    let div1 = Value::Array(vec![Value::Array(vec![Value::from(2)])]);
+    let div2 = Value::Array(vec![Value::Array(vec![Value::from(6)])]);
+
+    sorted.push(div1.clone());
+    sorted.push(div2.clone());
+
+    sorted.sort_by(compare);
+
+    let mut div1_idx = 0;
+    let mut div2_idx = 0;
+
+    for (i, el) in sorted.iter().enumerate() {
+        let div1_cmp = compare(el, &div1);
+        let div2_cmp = compare(el, &div2);
+
+        if div1_cmp == Ordering::Equal {
+            div1_idx = i;
+        }
+
+        if div2_cmp == Ordering::Equal {
+            div2_idx = i;
+        }
+    }
+
+    (div1_idx + 1) * (div2_idx + 1)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Vec<Value>>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(pairs: &Self::Input) -> Answer {
+        part1(pairs).into()
+    }
+
+    fn part2(pairs: &Self::Input) -> Answer {
+        part2(pairs).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::read_to_string;
+
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let pairs = parse(&read_to_string("./data/demo.txt").unwrap()).unwrap();
+        let part1_res = part1(&pairs);
+        assert_eq!(part1_res, 13);
+    }
+
+    #[test]
+    fn part2_works() {
+        let pairs = parse(&read_to_string("./data/demo.txt").unwrap()).unwrap();
+        let part2_res = part2(&pairs);
+        assert_eq!(part2_res, 140);
+    }
+}
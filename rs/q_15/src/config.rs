@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use crate::{Coord, Sensor};
+
+/// Where to look: the row counted in part 1, the area searched in part 2 and how the tuning
+/// frequency of the distress beacon is computed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchConfig {
+    pub target_row: isize,
+    pub min_x: isize,
+    pub max_x: isize,
+    pub min_y: isize,
+    pub max_y: isize,
+    pub frequency_multiplier: isize,
+}
+
+impl SearchConfig {
+    /// The values used by the example in the puzzle text.
+    pub const DEMO: SearchConfig = SearchConfig {
+        target_row: 10,
+        min_x: 0,
+        max_x: 20,
+        min_y: 0,
+        max_y: 20,
+        frequency_multiplier: 4_000_000,
+    };
+
+    /// The values used by the real puzzle inputs.
+    pub const REAL: SearchConfig = SearchConfig {
+        target_row: 2_000_000,
+        min_x: 0,
+        max_x: 4_000_000,
+        min_y: 0,
+        max_y: 4_000_000,
+        frequency_multiplier: 4_000_000,
+    };
+
+    /// Pick between the demo and the real values from how far the sensors are spread out.
+    ///
+    /// The example's sensors all sit within a few dozen cells of the origin while real inputs
+    /// span millions of cells.
+    pub fn guess(sensors: &HashMap<Coord, Sensor>) -> Self {
+        let is_demo = sensors
+            .keys()
+            .all(|pos| pos.0.abs() <= 1_000 && pos.1.abs() <= 1_000);
+
+        match is_demo {
+            true => Self::DEMO,
+            false => Self::REAL,
+        }
+    }
+
+    /// `None` if the frequency is negative or too large, which a search area off the puzzle's
+    /// allows.
+    pub fn tuning_frequency(&self, beacon: Coord) -> Option<usize> {
+        let frequency = beacon
+            .0
+            .checked_mul(self.frequency_multiplier)?
+            .checked_add(beacon.1)?;
+
+        usize::try_from(frequency).ok()
+    }
+}
+
+/// Values given on the command line, applied on top of another config.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ConfigOverrides {
+    target_row: Option<isize>,
+    min_x: Option<isize>,
+    max_x: Option<isize>,
+    min_y: Option<isize>,
+    max_y: Option<isize>,
+    frequency_multiplier: Option<isize>,
+}
+
+impl ConfigOverrides {
+    /// Take the search options out of `args`, returning them along with the remaining arguments.
+    ///
+    /// `--max` sets both `--max-x` and `--max-y`.
+    pub fn from_args<I>(args: I) -> Result<(Self, Vec<String>), String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut res = Self::default();
+        let mut rest = vec![];
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let fields: &mut [&mut Option<isize>] = match arg.as_str() {
+                "--row" => &mut [&mut res.target_row],
+                "--min-x" => &mut [&mut res.min_x],
+                "--max-x" => &mut [&mut res.max_x],
+                "--min-y" => &mut [&mut res.min_y],
+                "--max-y" => &mut [&mut res.max_y],
+                "--max" => &mut [&mut res.max_x, &mut res.max_y],
+                "--multiplier" => &mut [&mut res.frequency_multiplier],
+                _ => {
+                    rest.push(arg);
+                    continue;
+                }
+            };
+
+            let value = args
+                .next()
+                .ok_or_else(|| format!("Missing value for {}", arg))?;
+            let value = value
+                .parse()
+                .map_err(|_| format!("Invalid value for {}: {}", arg, value))?;
+            for field in fields.iter_mut() {
+                **field = Some(value);
+            }
+        }
+
+        Ok((res, rest))
+    }
+
+    pub fn apply(&self, config: SearchConfig) -> SearchConfig {
+        SearchConfig {
+            target_row: self.target_row.unwrap_or(config.target_row),
+            min_x: self.min_x.unwrap_or(config.min_x),
+            max_x: self.max_x.unwrap_or(config.max_x),
+            min_y: self.min_y.unwrap_or(config.min_y),
+            max_y: self.max_y.unwrap_or(config.max_y),
+            frequency_multiplier: self
+                .frequency_multiplier
+                .unwrap_or(config.frequency_multiplier),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn from_args_works() {
+        let (overrides, rest) =
+            ConfigOverrides::from_args(args(&["--row", "11", "--demo", "--max", "30"])).unwrap();
+        let config = overrides.apply(SearchConfig::DEMO);
+
+        assert_eq!(rest, ["--demo"]);
+        assert_eq!(config.target_row, 11);
+        assert_eq!((config.max_x, config.max_y), (30, 30));
+        assert_eq!(config.min_x, 0);
+
+        assert!(ConfigOverrides::from_args(args(&["--row"])).is_err());
+        assert!(ConfigOverrides::from_args(args(&["--row", "ten"])).is_err());
+    }
+
+    #[test]
+    fn tuning_frequency_works() {
+        let config = SearchConfig::DEMO;
+        assert_eq!(config.tuning_frequency((14, 11)), Some(56000011));
+        assert_eq!(config.tuning_frequency((-1, 5)), None);
+        assert_eq!(config.tuning_frequency((0, -1)), None);
+        assert_eq!(config.tuning_frequency((isize::MAX, 0)), None);
+    }
+}
@@ -0,0 +1,355 @@
+use aoc_common::{parse_lines, parse_number, Answer, IntervalSet, ParseError, Result, Solution};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+
+pub use crate::{
+    config::{ConfigOverrides, SearchConfig},
+    par_part2::part2_par,
+    render::{coverage_image, render_window, write_pgm, write_ppm, Pixel},
+};
+
+use crate::{par_part2::par_get_beacon_pos, perimeter::perimeter_beacon_pos};
+
+mod config;
+mod par_part2;
+mod perimeter;
+mod render;
+
+pub type Coord = (isize, isize);
+
+#[derive(Debug, PartialEq)]
+pub struct Sensor {
+    distance: u32,
+    beacon: Coord,
+}
+
+fn parse_line(line: &str) -> Result<(Coord, Sensor)> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(
+            r#"Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)"#
+        )
+        .unwrap();
+    }
+
+    let captures = RE.captures(line).ok_or_else(|| {
+        ParseError::new("expected `Sensor at x=<x>, y=<y>: closest beacon is at x=<x>, y=<y>`")
+            .with_text(line)
+    })?;
+    let get_capture = |i| {
+        let capture = captures.get(i).unwrap().as_str();
+        parse_number::<isize>(capture).map_err(|err| err.locate(line, capture))
+    };
+
+    let pos = (get_capture(1)?, get_capture(2)?);
+    let beacon = (get_capture(3)?, get_capture(4)?);
+
+    Ok((
+        pos,
+        Sensor {
+            distance: manhattan_distance(&pos, &beacon),
+            beacon,
+        },
+    ))
+}
+
+fn manhattan_distance(a: &Coord, b: &Coord) -> u32 {
+    ((a.0 - b.0).abs() + (a.1 - b.1).abs()) as u32
+}
+
+pub fn parse_input(input: &str) -> Result<(HashMap<Coord, Sensor>, HashSet<Coord>)> {
+    let mut sensors = HashMap::new();
+    let mut beacons = HashSet::new();
+    for (coord, sensor) in parse_lines(input, parse_line)? {
+        beacons.insert(sensor.beacon);
+        sensors.insert(coord, sensor);
+    }
+
+    Ok((sensors, beacons))
+}
+
+/// The sensor readings along with where to look for the beacons.
+#[derive(Debug)]
+pub struct Scan {
+    sensors: HashMap<Coord, Sensor>,
+    beacons: HashSet<Coord>,
+    config: SearchConfig,
+}
+
+impl Scan {
+    /// Pair the readings with `config` instead of the one guessed from the input.
+    pub fn with_config(self, config: SearchConfig) -> Self {
+        Self { config, ..self }
+    }
+
+    pub fn sensors(&self) -> &HashMap<Coord, Sensor> {
+        &self.sensors
+    }
+
+    pub fn beacons(&self) -> &HashSet<Coord> {
+        &self.beacons
+    }
+
+    pub fn config(&self) -> &SearchConfig {
+        &self.config
+    }
+}
+
+/// The columns of `target_row` covered by at least one sensor.
+fn get_row_ranges(sensors: &HashMap<Coord, Sensor>, target_row: isize) -> IntervalSet<isize> {
+    sensors
+        .iter()
+        .filter(|(coords, sensor)| {
+            let diff = (coords.1 - target_row).abs();
+            (diff as u32) <= sensor.distance
+        })
+        .map(|(coords, sensor)| {
+            let r_pos = (coords.0, target_row);
+            let half_width = (sensor.distance - manhattan_distance(coords, &r_pos)) as isize;
+            r_pos.0 - half_width..r_pos.0 + half_width + 1
+        })
+        .collect()
+}
+
+pub fn part1(
+    sensors: &HashMap<Coord, Sensor>,
+    beacons: &HashSet<Coord>,
+    config: &SearchConfig,
+) -> usize {
+    let target_row = config.target_row;
+    let count = get_row_ranges(sensors, target_row).covered_len();
+
+    let beacon_count = beacons
+        .iter()
+        .filter(|beacon| beacon.1 == target_row)
+        .count();
+
+    count as usize - beacon_count
+}
+
+/// The ways part 2 can search for the distress beacon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part2Solver {
+    /// Look for an uncovered cell one row at a time
+    RowScan,
+    /// The row scan, spread over every core
+    Parallel,
+    /// Intersect the lines just outside every sensor's range, falling back to the row scan when
+    /// the free cell isn't on them, e.g. in an area far from every sensor
+    Perimeter,
+}
+
+impl Part2Solver {
+    pub const ALL: [Part2Solver; 3] = [
+        Part2Solver::RowScan,
+        Part2Solver::Parallel,
+        Part2Solver::Perimeter,
+    ];
+
+    pub fn find_beacon(
+        self,
+        sensors: &HashMap<Coord, Sensor>,
+        beacons: &HashSet<Coord>,
+        config: &SearchConfig,
+    ) -> Option<Coord> {
+        match self {
+            Part2Solver::RowScan => get_beacon_pos(sensors, beacons, config),
+            Part2Solver::Parallel => par_get_beacon_pos(sensors, beacons, config),
+            Part2Solver::Perimeter => perimeter_beacon_pos(sensors, beacons, config)
+                .or_else(|| get_beacon_pos(sensors, beacons, config)),
+        }
+    }
+}
+
+pub fn part2(
+    sensors: &HashMap<Coord, Sensor>,
+    beacons: &HashSet<Coord>,
+    config: &SearchConfig,
+) -> Option<usize> {
+    part2_with(Part2Solver::Perimeter, sensors, beacons, config)
+}
+
+/// The tuning frequency of the distress beacon, or `None` if every cell of the search area is
+/// covered or the frequency doesn't fit.
+pub fn part2_with(
+    solver: Part2Solver,
+    sensors: &HashMap<Coord, Sensor>,
+    beacons: &HashSet<Coord>,
+    config: &SearchConfig,
+) -> Option<usize> {
+    let beacon = solver.find_beacon(sensors, beacons, config)?;
+
+    config.tuning_frequency(beacon)
+}
+
+fn get_beacon_pos(
+    sensors: &HashMap<Coord, Sensor>,
+    beacons: &HashSet<Coord>,
+    config: &SearchConfig,
+) -> Option<Coord> {
+    for i in config.min_y..=config.max_y {
+        let beacon = row_search_beacon(sensors, beacons, i, config);
+        if beacon.is_some() {
+            return beacon;
+        }
+    }
+
+    None
+}
+
+pub fn row_search_beacon(
+    sensors: &HashMap<Coord, Sensor>,
+    beacons: &HashSet<Coord>,
+    target_row: isize,
+    config: &SearchConfig,
+) -> Option<Coord> {
+    let uncovered = get_row_ranges(sensors, target_row).complement(config.min_x..config.max_x + 1);
+
+    for range in uncovered.iter() {
+        if let Some(x) = range.clone().find(|x| !beacons.contains(&(*x, target_row))) {
+            return Some((x, target_row));
+        }
+    }
+
+    None
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Scan;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (sensors, beacons) = parse_input(input)?;
+        let config = SearchConfig::guess(&sensors);
+
+        Ok(Scan {
+            sensors,
+            beacons,
+            config,
+        })
+    }
+
+    fn part1(scan: &Self::Input) -> Answer {
+        part1(&scan.sensors, &scan.beacons, &scan.config).into()
+    }
+
+    fn part2(scan: &Self::Input) -> Answer {
+        part2(&scan.sensors, &scan.beacons, &scan.config).map_or(Answer::NoSolution, Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEMO: &str = include_str!("../data/demo.txt");
+
+    #[test]
+    fn parse_line_works() {
+        assert_eq!(
+            parse_line("Sensor at x=2, y=18: closest beacon is at x=-2, y=15"),
+            Ok((
+                (2, 18),
+                Sensor {
+                    beacon: (-2, 15),
+                    distance: 7,
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn part1_works() {
+        let (sensors, beacons) = parse_input(DEMO).unwrap();
+        assert_eq!(part1(&sensors, &beacons, &SearchConfig::DEMO), 26);
+    }
+
+    #[test]
+    fn search_row_beacon_works() {
+        let (sensors, beacons) = parse_input(DEMO).unwrap();
+        let config = SearchConfig::DEMO;
+        assert_eq!(
+            row_search_beacon(&sensors, &beacons, 11, &config).unwrap(),
+            (14, 11)
+        );
+        assert!(row_search_beacon(&sensors, &beacons, 10, &config).is_none());
+        assert!(row_search_beacon(&sensors, &beacons, 9, &config).is_none());
+    }
+
+    #[test]
+    fn part2_works() {
+        let (sensors, beacons) = parse_input(DEMO).unwrap();
+        for solver in Part2Solver::ALL {
+            assert_eq!(
+                part2_with(solver, &sensors, &beacons, &SearchConfig::DEMO),
+                Some(56000011),
+                "{:?}",
+                solver
+            );
+        }
+    }
+
+    #[test]
+    fn part2_without_free_cell_works() {
+        let (sensors, beacons) = parse_input(DEMO).unwrap();
+        // the sensor at (8, 7) covers all of this area
+        let config = SearchConfig {
+            min_x: 6,
+            max_x: 10,
+            min_y: 5,
+            max_y: 9,
+            ..SearchConfig::DEMO
+        };
+        for solver in Part2Solver::ALL {
+            assert_eq!(
+                part2_with(solver, &sensors, &beacons, &config),
+                None,
+                "{:?}",
+                solver
+            );
+        }
+        let scan = Day15::parse(DEMO).unwrap().with_config(config);
+        assert_eq!(Day15::part2(&scan), Answer::NoSolution);
+    }
+
+    #[test]
+    fn part2_far_from_sensors_works() {
+        let (sensors, beacons) = parse_input(DEMO).unwrap();
+        // no sensor's range borders this row, so its free cells are on none of their lines
+        let config = SearchConfig {
+            min_x: 100,
+            max_x: 102,
+            min_y: 100,
+            max_y: 100,
+            ..SearchConfig::DEMO
+        };
+        assert_eq!(perimeter_beacon_pos(&sensors, &beacons, &config), None);
+        for solver in Part2Solver::ALL {
+            assert_eq!(
+                part2_with(solver, &sensors, &beacons, &config),
+                Some(400000100),
+                "{:?}",
+                solver
+            );
+        }
+    }
+
+    #[test]
+    fn config_guess_works() {
+        let scan = Day15::parse(DEMO).unwrap();
+        assert_eq!(scan.config(), &SearchConfig::DEMO);
+        assert_eq!(Day15::part1(&scan), 26.into());
+
+        // outside of the default area the distress beacon isn't the only uncovered cell
+        let scan = scan.with_config(SearchConfig {
+            min_x: 15,
+            max_x: 25,
+            ..SearchConfig::DEMO
+        });
+        assert_eq!(
+            Part2Solver::RowScan.find_beacon(&scan.sensors, &scan.beacons, scan.config()),
+            Some((25, 4))
+        );
+    }
+}
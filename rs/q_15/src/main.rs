@@ -1,306 +1,130 @@
-use itertools::Itertools;
-use lazy_static::lazy_static;
-use regex::Regex;
-use std::{
-    cmp::Ordering,
-    collections::{HashMap, HashSet},
-    fs::read_to_string,
-    ops::RangeInclusive,
-    time::Instant,
-};
-
-use crate::par_part2::part2_par;
-
-mod par_part2;
-
-pub type Coord = (isize, isize);
-
-#[derive(Debug, PartialEq)]
-pub struct Sensor {
-    distance: u32,
-    beacon: Coord,
-}
-
-fn parse_line(line: &str) -> (Coord, Sensor) {
-    lazy_static! {
-        static ref RE: Regex = Regex::new(
-            r#"Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)"#
-        )
-        .unwrap();
-    }
-
-    let captures = RE.captures(line).unwrap();
-    let get_capture = |i| captures.get(i).unwrap().as_str().parse::<isize>().unwrap();
-
-    let pos = (get_capture(1), get_capture(2));
-    let beacon = (get_capture(3), get_capture(4));
-
-    (
-        pos,
-        Sensor {
-            distance: manhattan_distance(&pos, &beacon),
-            beacon,
-        },
-    )
-}
-
-fn manhattan_distance(a: &Coord, b: &Coord) -> u32 {
-    ((a.0 - b.0).abs() + (a.1 - b.1).abs()) as u32
-}
-
-fn parse_input(input: &str) -> (HashMap<Coord, Sensor>, HashSet<Coord>) {
-    let iter = input.lines().map(parse_line);
-    let mut sensors = HashMap::new();
-    let mut beacons = HashSet::new();
-    for (coord, sensor) in iter {
-        beacons.insert(sensor.beacon);
-        sensors.insert(coord, sensor);
-    }
-
-    (sensors, beacons)
-}
-
-fn get_row_ranges(
-    sensors: &HashMap<Coord, Sensor>,
-    target_row: isize,
-) -> Vec<RangeInclusive<isize>> {
-    let mut ranges = sensors
-        .iter()
-        .filter(|(coords, sensor)| {
-            let diff = (coords.1 - target_row).abs();
-            (diff as u32) <= sensor.distance
-        })
-        .map(|(coords, sensor)| {
-            let r_pos = (coords.0, target_row);
-            let md_diff = sensor.distance - manhattan_distance(coords, &r_pos);
-            let row_width = 2 * md_diff + 1;
-            let min_x = r_pos.0 - (row_width as isize / 2);
-            let max_x = r_pos.0 + (row_width as isize / 2);
-            min_x..=max_x
-        })
-        .collect_vec();
-
-    ranges.sort_by(|a, b| {
-        let diff = a.start() - b.start();
-        if diff == 0 {
-            return Ordering::Equal;
-        }
-
-        if diff > 0 {
-            return Ordering::Greater;
-        }
-
-        Ordering::Less
-    });
-
-    ranges
-}
-
-fn part1(sensors: &HashMap<Coord, Sensor>, beacons: &HashSet<Coord>, target_row: isize) -> usize {
-    let ranges = get_row_ranges(sensors, target_row);
-
-    let count: isize = merge_all_ranges(&ranges)
-        .iter()
-        .map(|range| (range.start() - range.end()).abs() + 1)
-        .sum();
-
-    let beacon_count = beacons
-        .iter()
-        .filter(|beacon| beacon.1 == target_row)
-        .count();
-
-    count as usize - beacon_count
-}
-
-fn part2(sensors: &HashMap<Coord, Sensor>, beacons: &HashSet<Coord>, max: isize) -> usize {
-    let beacon = get_beacon_pos(sensors, beacons, max).unwrap();
-
-    beacon.0 as usize * 4_000_000 + beacon.1 as usize
-}
-
-fn range_intersects<T: PartialOrd>(first: &RangeInclusive<T>, second: &RangeInclusive<T>) -> bool {
-    (first.start() <= second.end() && first.end() >= second.end())
-        || (first.end() >= second.start() && second.end() >= first.end())
-}
-
-fn merge_ranges<T: Ord + Copy>(
-    first: &RangeInclusive<T>,
-    second: &RangeInclusive<T>,
-) -> RangeInclusive<T> {
-    *first.start().min(second.start())..=*first.end().max(second.end())
-}
-
-fn merge_all_ranges(ranges: &Vec<RangeInclusive<isize>>) -> Vec<RangeInclusive<isize>> {
-    if ranges.len() <= 1 {
-        return ranges.clone();
-    }
-
-    let mut res = vec![ranges[0].clone()];
+use std::{env, fs::File, io::BufWriter, path::Path, process};
 
-    for range in ranges.iter().skip(1) {
-        let last = res.last_mut().unwrap();
-        if range_intersects(last, range) {
-            *last = merge_ranges(last, range);
-        } else {
-            res.push(range.clone());
-        }
-    }
+use aoc_common::{measure, BenchConfig, BenchReport, InputSource, Solution, Timings};
+use q_15::{
+    coverage_image, part2_with, render_window, write_pgm, write_ppm, ConfigOverrides, Day15,
+    Part2Solver, Scan, SearchConfig,
+};
 
-    res
-}
+/// The side of the exported image, in pixels.
+const IMAGE_SIZE: usize = 1000;
 
-fn trim_range(
-    range: &RangeInclusive<isize>,
-    cutoff: &RangeInclusive<isize>,
-) -> RangeInclusive<isize> {
-    let start = cutoff.start().max(range.start());
-    let end = cutoff.end().min(range.end());
-    *start..=*end
+/// What to draw on top of solving, taken out of the arguments before the search options.
+#[derive(Debug, Default)]
+struct Output {
+    render: bool,
+    image: Option<String>,
 }
 
-fn get_beacon_pos(
-    sensors: &HashMap<Coord, Sensor>,
-    beacons: &HashSet<Coord>,
-    search_max: isize,
-) -> Option<Coord> {
-    for i in 0..search_max {
-        let beacon = row_search_beacon(sensors, beacons, i, search_max);
-        if beacon.is_some() {
-            return beacon;
+fn parse_output(args: impl IntoIterator<Item = String>) -> Result<(Output, Vec<String>), String> {
+    let mut output = Output::default();
+    let mut rest = vec![];
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--render" => output.render = true,
+            "--image" => {
+                let path = args.next().ok_or("Missing value for --image")?;
+                if !path.ends_with(".pgm") && !path.ends_with(".ppm") {
+                    return Err(format!("Expected a .pgm or .ppm image, got {}", path));
+                }
+                output.image = Some(path);
+            }
+            _ => rest.push(arg),
         }
     }
 
-    None
+    Ok((output, rest))
 }
 
-pub fn row_search_beacon(
-    sensors: &HashMap<Coord, Sensor>,
-    beacons: &HashSet<Coord>,
-    target_row: isize,
-    search_max: isize,
-) -> Option<Coord> {
-    let ranges = get_row_ranges(sensors, target_row);
-    let mut merged = merge_all_ranges(&ranges);
-
-    for range in merged.iter_mut() {
-        *range = trim_range(range, &(0..=search_max));
+fn export_image(scan: &Scan, path: &str) -> std::io::Result<()> {
+    let image = coverage_image(
+        scan.sensors(),
+        scan.beacons(),
+        scan.config(),
+        IMAGE_SIZE,
+        IMAGE_SIZE,
+    );
+    let out = BufWriter::new(File::create(path)?);
+
+    match path.ends_with(".pgm") {
+        true => write_pgm(&image, out),
+        false => write_ppm(&image, out),
     }
-
-    let mut iter = merged.iter().enumerate().peekable();
-
-    while let Some((i, range)) = iter.next() {
-        let start = range.start();
-        let start_coord = (*start, target_row);
-        if i == 0 && *start > 0 && !beacons.contains(&start_coord) {
-            return Some(start_coord);
-        }
-
-        let end = range.end() + 1;
-        if end > search_max || beacons.contains(&(end, target_row)) {
-            continue;
-        }
-
-        if let Some((_, next)) = iter.peek() {
-            if !next.contains(&end) {
-                return Some((end, target_row));
-            }
-        } else {
-            return Some((end, target_row));
-        }
-    }
-
-    None
 }
 
 fn main() {
-    let timer = Instant::now();
-    let input = read_to_string("./data/input.txt").unwrap();
-    let read_elapsed = timer.elapsed();
-    println!("Reading input took: {:.2?}", read_elapsed);
-
-    let timer = Instant::now();
-    let (sensors, beacons) = parse_input(&input);
-    let parse_elapsed = timer.elapsed();
-    println!("Parsing took: {:.2?}", parse_elapsed);
-
-    let timer = Instant::now();
-    let p1_res = part1(&sensors, &beacons, 2_000_000);
-    let p1_elapsed = timer.elapsed();
-    println!("Part 1: {}", p1_res);
-    println!("Part 1 took: {:.2?}", p1_elapsed);
-
-    let timer = Instant::now();
-    let p2_res = part2(&sensors, &beacons, 4_000_000);
-    let p2_elapsed = timer.elapsed();
-    println!("Part 2: {}", p2_res);
-    println!("Part 2 took: {:.2?}", p2_elapsed);
-
-    let timer = Instant::now();
-    let p2_res = part2_par(&sensors, &beacons, 4_000_000);
-    let p2_elapsed = timer.elapsed();
-    println!("Part 2 par: {}", p2_res);
-    println!("Part 2 par took: {:.2?}", p2_elapsed);
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const DEMO: &'static str = include_str!("../data/demo.txt");
+    let res = parse_output(env::args().skip(1)).and_then(|(output, rest)| {
+        let (overrides, rest) = ConfigOverrides::from_args(rest)?;
+        let source = InputSource::from_args(rest)?;
+        let input = source.read(Path::new(env!("CARGO_MANIFEST_DIR")))?;
+        Ok((output, overrides, source, input))
+    });
+    let (output, overrides, source, input) = res.unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        eprintln!(
+            "Usage: [--row <y>] [--min-x <x>] [--max-x <x>] [--min-y <y>] [--max-y <y>] \
+             [--max <n>] [--multiplier <n>] [--render] [--image <path.pgm|path.ppm>] \
+             [--demo | - | <path>]"
+        );
+        process::exit(1);
+    });
 
-    #[test]
-    fn parse_line_works() {
-        assert_eq!(
-            parse_line("Sensor at x=2, y=18: closest beacon is at x=-2, y=15"),
-            (
-                (2, 18),
-                Sensor {
-                    beacon: (-2, 15),
-                    distance: 7,
-                }
+    // the row scans go over millions of rows, so a single run is enough
+    let config = BenchConfig::ONCE;
+    let (scan, parse) = measure(&config, || Day15::parse(&input));
+    let scan = scan.unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        process::exit(1);
+    });
+    let base = match source {
+        InputSource::Demo => SearchConfig::DEMO,
+        _ => *scan.config(),
+    };
+    let scan = scan.with_config(overrides.apply(base));
+
+    if output.render {
+        let config = scan.config();
+        println!(
+            "{}\n",
+            render_window(
+                scan.sensors(),
+                scan.beacons(),
+                config.min_x..=config.max_x,
+                config.min_y..=config.max_y,
             )
         );
     }
-
-    #[test]
-    fn range_includes_works() {
-        assert!(range_intersects(&(1..=4), &(1..=3)));
-        assert!(range_intersects(&(1..=4), &(1..=4)));
-        assert!(range_intersects(&(2..=6), &(3..=6)));
-        assert!(range_intersects(&(2..=6), &(3..=5)));
-
-        assert!(range_intersects(&(1..=4), &(0..=1)));
-        assert!(range_intersects(&(1..=4), &(3..=5)));
-
-        assert!(!range_intersects(&(1..=4), &(5..=6)));
-        assert!(!range_intersects(&(3..=5), &(0..=2)));
-    }
-
-    #[test]
-    fn merge_ranges_works() {
-        assert_eq!(merge_ranges(&(1..=3), &(2..=5)), 1..=5);
-    }
-
-    #[test]
-    fn part1_works() {
-        let (sensors, beacons) = parse_input(DEMO);
-        assert_eq!(part1(&sensors, &beacons, 10), 26);
-    }
-
-    #[test]
-    fn search_row_beacon_works() {
-        let (sensors, beacons) = parse_input(DEMO);
-        assert_eq!(
-            row_search_beacon(&sensors, &beacons, 11, 20).unwrap(),
-            (14, 11)
-        );
-        assert!(row_search_beacon(&sensors, &beacons, 10, 20).is_none());
-        assert!(row_search_beacon(&sensors, &beacons, 9, 20).is_none());
+    if let Some(path) = &output.image {
+        if let Err(err) = export_image(&scan, path) {
+            eprintln!("Couldn't write {}: {}", path, err);
+            process::exit(1);
+        }
     }
-
-    #[test]
-    fn part2_works() {
-        let (sensors, beacons) = parse_input(DEMO);
-        assert_eq!(part2(&sensors, &beacons, 20), 56000011);
+    let (answer1, part1) = measure(&config, || Day15::part1(&scan));
+    let (answer2, part2) = measure(&config, || Day15::part2(&scan));
+
+    println!("Part 1: {}", answer1);
+    println!("Part 2: {}", answer2);
+
+    let mut report = BenchReport::default();
+    report.add(
+        15,
+        Timings {
+            parse,
+            part1,
+            part2,
+        },
+    );
+    print!("{}", report.to_table());
+
+    let (sensors, beacons) = (scan.sensors(), scan.beacons());
+    for solver in Part2Solver::ALL {
+        let (res, stats) = measure(&config, || {
+            part2_with(solver, sensors, beacons, scan.config())
+        });
+        let res = res.map_or("no free cell".to_owned(), |res| res.to_string());
+        println!("Part 2 {:?}: {} ({:.2?})", solver, res, stats.median);
     }
 }
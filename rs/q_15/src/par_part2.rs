@@ -1,20 +1,22 @@
 use rayon::prelude::*;
 use std::collections::{HashMap, HashSet};
 
-use crate::{row_search_beacon, Coord, Sensor};
+use crate::{part2_with, row_search_beacon, Coord, Part2Solver, SearchConfig, Sensor};
 
-pub fn part2_par(sensors: &HashMap<Coord, Sensor>, beacons: &HashSet<Coord>, max: isize) -> usize {
-    let beacon = par_get_beacon_pos(sensors, beacons, max).unwrap();
-
-    beacon.0 as usize * 4_000_000 + beacon.1 as usize
+pub fn part2_par(
+    sensors: &HashMap<Coord, Sensor>,
+    beacons: &HashSet<Coord>,
+    config: &SearchConfig,
+) -> Option<usize> {
+    part2_with(Part2Solver::Parallel, sensors, beacons, config)
 }
 
-fn par_get_beacon_pos(
+pub fn par_get_beacon_pos(
     sensors: &HashMap<Coord, Sensor>,
     beacons: &HashSet<Coord>,
-    search_max: isize,
+    config: &SearchConfig,
 ) -> Option<Coord> {
-    (0..=search_max)
+    (config.min_y..=config.max_y)
         .into_par_iter()
-        .find_map_any(|i| row_search_beacon(sensors, beacons, i, search_max))
+        .find_map_any(|i| row_search_beacon(sensors, beacons, i, config))
 }
@@ -0,0 +1,47 @@
+use itertools::iproduct;
+use std::collections::{HashMap, HashSet};
+
+use crate::{manhattan_distance, Coord, SearchConfig, Sensor};
+
+/// Find the distress beacon without scanning rows.
+///
+/// The only uncovered cell has to sit right outside the range of the sensors around it, so it lies
+/// on one of the diagonal lines bordering a sensor's diamond, `x + y = c` or `x - y = c`, and on
+/// a second such line or an edge of the search area. Intersecting every pair of lines leaves
+/// O(sensors²) candidates to check.
+pub fn perimeter_beacon_pos(
+    sensors: &HashMap<Coord, Sensor>,
+    beacons: &HashSet<Coord>,
+    config: &SearchConfig,
+) -> Option<Coord> {
+    // in rotated coordinates, u = x + y and v = x - y
+    let mut sums = HashSet::new();
+    let mut diffs = HashSet::new();
+    for (pos, sensor) in sensors {
+        let outside = sensor.distance as isize + 1;
+        sums.extend([pos.0 + pos.1 - outside, pos.0 + pos.1 + outside]);
+        diffs.extend([pos.0 - pos.1 - outside, pos.0 - pos.1 + outside]);
+    }
+
+    let crossings = iproduct!(&sums, &diffs)
+        .filter(|(u, v)| (*u + *v) % 2 == 0)
+        .map(|(u, v)| ((u + v) / 2, (u - v) / 2));
+    let x_edges = [config.min_x, config.max_x];
+    let y_edges = [config.min_y, config.max_y];
+    let on_edges = iproduct!(&sums, x_edges)
+        .map(|(u, x)| (x, u - x))
+        .chain(iproduct!(&sums, y_edges).map(|(u, y)| (u - y, y)))
+        .chain(iproduct!(&diffs, x_edges).map(|(v, x)| (x, x - v)))
+        .chain(iproduct!(&diffs, y_edges).map(|(v, y)| (v + y, y)));
+
+    crossings.chain(on_edges).find(|pos| {
+        let in_bounds = (config.min_x..=config.max_x).contains(&pos.0)
+            && (config.min_y..=config.max_y).contains(&pos.1);
+
+        in_bounds
+            && !beacons.contains(pos)
+            && sensors
+                .iter()
+                .all(|(sensor_pos, sensor)| manhattan_distance(sensor_pos, pos) > sensor.distance)
+    })
+}
@@ -0,0 +1,240 @@
+use aoc_common::{Grid, IntervalSet};
+use std::{
+    collections::{HashMap, HashSet},
+    io::{self, Write},
+    ops::RangeInclusive,
+};
+
+use crate::{get_row_ranges, Coord, SearchConfig, Sensor};
+
+/// Draw the cells of a window like the puzzle text does: `S` for sensors, `B` for beacons, `#` for
+/// cells covered by a sensor and `.` for the rest, one line per row.
+pub fn render_window(
+    sensors: &HashMap<Coord, Sensor>,
+    beacons: &HashSet<Coord>,
+    columns: RangeInclusive<isize>,
+    rows: RangeInclusive<isize>,
+) -> String {
+    rows.map(|y| {
+        let covered = get_row_ranges(sensors, y);
+        columns
+            .clone()
+            .map(|x| match (x, y) {
+                pos if sensors.contains_key(&pos) => 'S',
+                pos if beacons.contains(&pos) => 'B',
+                _ if covered.contains(&x) => '#',
+                _ => '.',
+            })
+            .collect::<String>()
+    })
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+/// One pixel of a downscaled coverage image, standing for a block of cells.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Pixel {
+    /// The share of the block's cells covered by a sensor, between 0 and 1
+    pub coverage: f64,
+    /// Whether any cell of the block is left uncovered
+    pub uncovered: bool,
+    pub sensor: bool,
+    pub beacon: bool,
+}
+
+impl Pixel {
+    /// Covered blocks are lighter, blocks with any uncovered cell are black so they stand out.
+    pub fn gray(&self) -> u8 {
+        match self.uncovered {
+            true => 0,
+            false => (self.coverage * 255.0).round() as u8,
+        }
+    }
+
+    /// Like [`Pixel::gray`], with sensors in green, beacons in blue and uncovered blocks in red.
+    pub fn rgb(&self) -> [u8; 3] {
+        match *self {
+            Pixel { sensor: true, .. } => [0, 200, 0],
+            Pixel { beacon: true, .. } => [0, 80, 255],
+            Pixel {
+                uncovered: true, ..
+            } => [255, 0, 0],
+            _ => [self.gray(); 3],
+        }
+    }
+}
+
+/// Splits `cells` into `pixels` consecutive blocks whose sizes differ by at most one.
+#[derive(Debug, Clone, Copy)]
+struct Scale {
+    start: isize,
+    cells: isize,
+    pixels: isize,
+}
+
+impl Scale {
+    fn new(range: RangeInclusive<isize>, pixels: usize) -> Self {
+        let cells = (range.end() - range.start() + 1).max(1);
+        Self {
+            start: *range.start(),
+            cells,
+            pixels: (pixels as isize).clamp(1, cells),
+        }
+    }
+
+    /// The first cell of block `pixel`. `pixel == self.pixels` gives the end of the last block.
+    fn block_start(&self, pixel: isize) -> isize {
+        self.start + pixel * self.cells / self.pixels
+    }
+
+    fn block_len(&self, pixel: isize) -> isize {
+        self.block_start(pixel + 1) - self.block_start(pixel)
+    }
+
+    fn pixel_of(&self, cell: isize) -> Option<usize> {
+        let offset = cell - self.start;
+        let pixel = ((offset + 1) * self.pixels - 1).div_euclid(self.cells);
+        (0 <= offset && offset < self.cells).then_some(pixel as usize)
+    }
+}
+
+/// Downscale the search area of `config` to a `width` by `height` image.
+///
+/// Every row of the area goes through [`get_row_ranges`], so the coverage of each pixel is exact
+/// and a single uncovered cell is enough to flag its block, however large the area is.
+pub fn coverage_image(
+    sensors: &HashMap<Coord, Sensor>,
+    beacons: &HashSet<Coord>,
+    config: &SearchConfig,
+    width: usize,
+    height: usize,
+) -> Grid<Pixel> {
+    let columns = Scale::new(config.min_x..=config.max_x, width);
+    let rows = Scale::new(config.min_y..=config.max_y, height);
+    let bounds = IntervalSet::from(config.min_x..config.max_x + 1);
+    let mut image = Grid::new(
+        columns.pixels as usize,
+        rows.pixels as usize,
+        Pixel::default(),
+    );
+
+    for row in 0..rows.pixels {
+        // cells covered at the ends of ranges, plus difference arrays of how many ranges span
+        // each block entirely and how many gaps touch it, so every range is added in constant time
+        let mut partial = vec![0; columns.pixels as usize];
+        let mut full = vec![0; columns.pixels as usize + 1];
+        let mut gaps = vec![0; columns.pixels as usize + 1];
+
+        for y in rows.block_start(row)..rows.block_start(row + 1) {
+            let covered = get_row_ranges(sensors, y).intersection(&bounds);
+
+            for range in covered.iter() {
+                let first = columns.pixel_of(range.start).unwrap();
+                let last = columns.pixel_of(range.end - 1).unwrap();
+                if first == last {
+                    partial[first] += range.end - range.start;
+                    continue;
+                }
+
+                partial[first] += columns.block_start(first as isize + 1) - range.start;
+                partial[last] += range.end - columns.block_start(last as isize);
+                full[first + 1] += 1;
+                full[last] -= 1;
+            }
+
+            for gap in covered.complement(config.min_x..config.max_x + 1).iter() {
+                let first = columns.pixel_of(gap.start).unwrap();
+                let last = columns.pixel_of(gap.end - 1).unwrap();
+                gaps[first] += 1;
+                gaps[last + 1] -= 1;
+            }
+        }
+
+        let (mut spanning, mut touching) = (0, 0);
+        for column in 0..columns.pixels as usize {
+            spanning += full[column];
+            touching += gaps[column];
+            image[(row as usize, column)].uncovered = touching > 0;
+            let block_len = columns.block_len(column as isize);
+            let covered = partial[column] + spanning * block_len;
+            let cells = block_len * rows.block_len(row);
+            image[(row as usize, column)].coverage = covered as f64 / cells as f64;
+        }
+    }
+
+    for pos in sensors.keys() {
+        if let (Some(column), Some(row)) = (columns.pixel_of(pos.0), rows.pixel_of(pos.1)) {
+            image[(row, column)].sensor = true;
+        }
+    }
+    for pos in beacons {
+        if let (Some(column), Some(row)) = (columns.pixel_of(pos.0), rows.pixel_of(pos.1)) {
+            image[(row, column)].beacon = true;
+        }
+    }
+
+    image
+}
+
+/// Write `image` as a binary grayscale PGM.
+pub fn write_pgm(image: &Grid<Pixel>, mut out: impl Write) -> io::Result<()> {
+    write!(out, "P5\n{} {}\n255\n", image.width(), image.height())?;
+    let bytes: Vec<_> = image.iter().map(|(_, pixel)| pixel.gray()).collect();
+    out.write_all(&bytes)
+}
+
+/// Write `image` as a binary color PPM.
+pub fn write_ppm(image: &Grid<Pixel>, mut out: impl Write) -> io::Result<()> {
+    write!(out, "P6\n{} {}\n255\n", image.width(), image.height())?;
+    let bytes: Vec<_> = image.iter().flat_map(|(_, pixel)| pixel.rgb()).collect();
+    out.write_all(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_input;
+
+    const DEMO: &str = include_str!("../data/demo.txt");
+
+    #[test]
+    fn render_window_works() {
+        let (sensors, beacons) = parse_input(DEMO).unwrap();
+
+        assert_eq!(
+            render_window(&sensors, &beacons, -4..=26, 10..=10),
+            "..####B######################.."
+        );
+        assert_eq!(
+            render_window(&sensors, &beacons, 0..=20, 10..=11),
+            "##B##################\nS#############.######"
+        );
+    }
+
+    #[test]
+    fn coverage_image_works() {
+        let (sensors, beacons) = parse_input(DEMO).unwrap();
+        let config = SearchConfig::DEMO;
+
+        let image = coverage_image(&sensors, &beacons, &config, 21, 21);
+        let uncovered: Vec<_> = image
+            .iter()
+            .filter(|(_, pixel)| pixel.uncovered)
+            .map(|(pos, _)| pos)
+            .collect();
+        assert_eq!(uncovered, [(11, 14)]);
+        assert!(image[(11, 0)].sensor && image[(10, 2)].beacon);
+        assert_eq!(image[(0, 0)].coverage, 1.0);
+
+        // every pixel of a 7x7 image is a block of 3x3 cells
+        let image = coverage_image(&sensors, &beacons, &config, 7, 7);
+        assert!(image[(3, 4)].uncovered);
+        assert_eq!(image[(3, 4)].coverage, 8.0 / 9.0);
+        assert_eq!(image.iter().filter(|(_, pixel)| pixel.uncovered).count(), 1);
+
+        let mut pgm = vec![];
+        write_pgm(&image, &mut pgm).unwrap();
+        assert!(pgm.starts_with(b"P5\n7 7\n255\n"));
+        assert_eq!(pgm.len(), 11 + 49);
+    }
+}
@@ -0,0 +1,105 @@
+use aoc_common::{parse_lines, parse_number, Answer, ParseError, Result, Solution};
+use itertools::Itertools;
+use std::collections::HashSet;
+
+pub type Coord = (isize, isize, isize);
+
+struct BoundingBox {
+    min_x: isize,
+    max_x: isize,
+    min_y: isize,
+    max_y: isize,
+    min_z: isize,
+    max_z: isize,
+}
+
+impl BoundingBox {
+    pub fn new(cubes: &HashSet<Coord>) -> Self {
+        Self {
+            min_x: cubes.iter().fold(isize::MAX, |acc, c| acc.min(c.0)) - 1,
+            max_x: cubes.iter().fold(isize::MIN, |acc, c| acc.max(c.0)) + 1,
+            min_y: cubes.iter().fold(isize::MAX, |acc, c| acc.min(c.1)) - 1,
+            max_y: cubes.iter().fold(isize::MIN, |acc, c| acc.max(c.1)) + 1,
+            min_z: cubes.iter().fold(isize::MAX, |acc, c| acc.min(c.2)) - 1,
+            max_z: cubes.iter().fold(isize::MIN, |acc, c| acc.max(c.2)) + 1,
+        }
+    }
+
+    pub fn is_inside(&self, pos: &Coord) -> bool {
+        pos.0 >= self.min_x
+            && pos.0 <= self.max_x
+            && pos.1 >= self.min_y
+            && pos.1 <= self.max_y
+            && pos.2 >= self.min_z
+            && pos.2 <= self.max_z
+    }
+
+    pub fn outside(&self, cubes: &HashSet<Coord>) -> HashSet<Coord> {
+        let mut res = HashSet::new();
+        let mut queue = Vec::from([(self.min_x, self.min_y, self.min_z)]);
+
+        while let Some(p) = queue.pop() {
+            if !cubes.contains(&p) && !res.contains(&p) && self.is_inside(&p) {
+                res.insert(p);
+                get_neighbours(&p).iter().for_each(|n| queue.push(*n));
+            }
+        }
+
+        res
+    }
+}
+
+fn parse_coord(input: &str) -> Result<Coord> {
+    input
+        .split(',')
+        .map(|n| parse_number::<isize>(n).map_err(|err| err.locate(input, n)))
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .collect_tuple()
+        .ok_or_else(|| ParseError::new("expected `x,y,z`").with_text(input))
+}
+
+fn sides_touching(pos: &Coord, cubes: &HashSet<Coord>) -> isize {
+    get_neighbours(pos)
+        .iter()
+        .filter(|c| cubes.contains(c))
+        .count() as isize
+}
+
+fn get_neighbours(pos: &Coord) -> Vec<Coord> {
+    vec![
+        (pos.0 - 1, pos.1, pos.2),
+        (pos.0 + 1, pos.1, pos.2),
+        (pos.0, pos.1 - 1, pos.2),
+        (pos.0, pos.1 + 1, pos.2),
+        (pos.0, pos.1, pos.2 - 1),
+        (pos.0, pos.1, pos.2 + 1),
+    ]
+}
+
+fn part1(cubes: &HashSet<Coord>) -> isize {
+    cubes.iter().map(|p| 6 - sides_touching(p, cubes)).sum()
+}
+
+fn part2(cubes: &HashSet<Coord>) -> isize {
+    let outside = BoundingBox::new(cubes).outside(cubes);
+    cubes.iter().map(|c| sides_touching(c, &outside)).sum()
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = HashSet<Coord>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_lines(input, parse_coord)?.into_iter().collect())
+    }
+
+    fn part1(cubes: &Self::Input) -> Answer {
+        part1(cubes).into()
+    }
+
+    fn part2(cubes: &Self::Input) -> Answer {
+        part2(cubes).into()
+    }
+}
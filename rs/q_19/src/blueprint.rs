@@ -1,3 +1,4 @@
+use aoc_common::{parse_lines, parse_number, ParseError, Result};
 use regex::Regex;
 
 use crate::{
@@ -29,24 +30,26 @@ impl Blueprint {
     }
 }
 
-pub fn parse_blueprints(input: &str) -> Vec<Blueprint> {
-    let reg = Regex::new(r"^Blueprint \d+: Each ore robot costs (\d+) ore. Each clay robot costs (\d+) ore. Each obsidian robot costs (\d+) ore and (\d+) clay. Each geode robot costs (\d+) ore and (\d+) obsidian.$").unwrap();
-
-    input
-        .lines()
-        .enumerate()
-        .map(|(line_num, line)| {
-            let captures = reg.captures(line).unwrap();
-            let get_i = |i: usize| captures.get(i).unwrap().as_str().parse::<u64>().unwrap();
-            Blueprint {
-                id: line_num + 1,
-                ore_robot: get_i(1),
-                clay_robot: get_i(2),
-                obsidian_robot: (get_i(3), get_i(4)),
-                geode_robot: (get_i(5), get_i(6)),
-            }
+pub fn parse_blueprints(input: &str) -> Result<Vec<Blueprint>> {
+    let reg = Regex::new(r"^Blueprint (\d+): Each ore robot costs (\d+) ore. Each clay robot costs (\d+) ore. Each obsidian robot costs (\d+) ore and (\d+) clay. Each geode robot costs (\d+) ore and (\d+) obsidian.$").unwrap();
+
+    parse_lines(input, |line| {
+        let captures = reg.captures(line).ok_or_else(|| {
+            ParseError::new("expected a blueprint listing the cost of each robot").with_text(line)
+        })?;
+        let get_i = |i: usize| {
+            let capture = captures.get(i).unwrap().as_str();
+            parse_number::<u64>(capture).map_err(|err| err.locate(line, capture))
+        };
+
+        Ok(Blueprint {
+            id: get_i(1)? as usize,
+            ore_robot: get_i(2)?,
+            clay_robot: get_i(3)?,
+            obsidian_robot: (get_i(4)?, get_i(5)?),
+            geode_robot: (get_i(6)?, get_i(7)?),
         })
-        .collect()
+    })
 }
 
 pub fn run_blueprint(limit: u64, blueprint: &Blueprint) -> u64 {
@@ -138,23 +141,15 @@ fn step_blueprint(
         // prune paths where we're trying to build a robot (except geode robots)
         // but the extra resources it would provide don't increase our purchasing power
         // (we can only build 1 robot per minute)
-        match next_action {
-            Action::BuildOre => {
-                if state.robots.ore_robot >= blueprint.get_max_ore_cost() {
-                    continue;
-                }
-            }
-            Action::BuildClay => {
-                if state.robots.clay_robot >= blueprint.obsidian_robot.1 {
-                    continue;
-                }
-            }
-            Action::BuildObsidian => {
-                if state.robots.obsidian_robot >= blueprint.geode_robot.1 {
-                    continue;
-                }
-            }
-            _ => (),
+        let is_redundant = match next_action {
+            Action::BuildOre => state.robots.ore_robot >= blueprint.get_max_ore_cost(),
+            Action::BuildClay => state.robots.clay_robot >= blueprint.obsidian_robot.1,
+            Action::BuildObsidian => state.robots.obsidian_robot >= blueprint.geode_robot.1,
+            _ => false,
+        };
+
+        if is_redundant {
+            continue;
         }
 
         let child = ActionNode {
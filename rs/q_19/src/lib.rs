@@ -0,0 +1,29 @@
+use aoc_common::{Answer, Result, Solution};
+
+use blueprint::{parse_blueprints, Blueprint};
+use part1::part1;
+use part2::part2;
+
+mod action;
+mod blueprint;
+mod part1;
+mod part2;
+mod state;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<Blueprint>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_blueprints(input)
+    }
+
+    fn part1(blueprints: &Self::Input) -> Answer {
+        part1(blueprints).into()
+    }
+
+    fn part2(blueprints: &Self::Input) -> Answer {
+        part2(blueprints).into()
+    }
+}
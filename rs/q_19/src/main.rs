@@ -1,18 +1,8 @@
-use std::fs::read_to_string;
-
-use blueprint::parse_blueprints;
-use part1::part1;
-use part2::part2;
-
-mod action;
-mod blueprint;
-mod part1;
-mod part2;
-mod state;
+use aoc_common::{load_parsed, Solution};
+use q_19::Day19;
 
 fn main() {
-    let input = read_to_string("./data/demo.txt").unwrap();
-    let blueprints = parse_blueprints(&input);
-    part1(&blueprints);
-    // part2(&blueprints);
+    let blueprints = load_parsed::<Day19>(env!("CARGO_MANIFEST_DIR"));
+    println!("Part 1: {}", Day19::part1(&blueprints));
+    // println!("Part 2: {}", Day19::part2(&blueprints));
 }
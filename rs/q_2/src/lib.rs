@@ -0,0 +1,123 @@
+use aoc_common::{parse_lines, Answer, ParseError, Result, Solution};
+
+use part1::part1;
+use part2::part2;
+
+mod part1;
+mod part2;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Hand {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Hand {
+    pub fn get_corresponding_hand(&self, result: &GameResult) -> Hand {
+        match result {
+            GameResult::Loss => match self {
+                Hand::Rock => Hand::Paper,
+                Hand::Paper => Hand::Scissors,
+                Hand::Scissors => Hand::Rock,
+            },
+            GameResult::Draw => self.clone(),
+            GameResult::Win => match self {
+                Hand::Rock => Hand::Scissors,
+                Hand::Paper => Hand::Rock,
+                Hand::Scissors => Hand::Paper,
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum GameResult {
+    Win,
+    Draw,
+    Loss,
+}
+
+/// The second column of the strategy guide, which means something different in each part
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Column {
+    X,
+    Y,
+    Z,
+}
+
+pub fn parse_opponent(input: &str) -> Result<Hand> {
+    match input {
+        "A" => Ok(Hand::Rock),
+        "B" => Ok(Hand::Paper),
+        "C" => Ok(Hand::Scissors),
+        _ => Err(ParseError::new("invalid opponent hand").with_text(input)),
+    }
+}
+
+pub fn parse_column(input: &str) -> Result<Column> {
+    match input {
+        "X" => Ok(Column::X),
+        "Y" => Ok(Column::Y),
+        "Z" => Ok(Column::Z),
+        _ => Err(ParseError::new("invalid strategy column").with_text(input)),
+    }
+}
+
+fn parse_round(line: &str) -> Result<(Hand, Column)> {
+    let (opponent, column) = line
+        .split_once(' ')
+        .ok_or_else(|| ParseError::new("expected two columns").with_text(line))?;
+
+    Ok((
+        parse_opponent(opponent).map_err(|err| err.locate(line, opponent))?,
+        parse_column(column).map_err(|err| err.locate(line, column))?,
+    ))
+}
+
+pub fn calculate_score(player: &Hand, result: &GameResult) -> u32 {
+    let hand_score: u32 = match player {
+        Hand::Rock => 1,
+        Hand::Paper => 2,
+        Hand::Scissors => 3,
+    };
+    let result_score: u32 = match result {
+        GameResult::Win => 6,
+        GameResult::Draw => 3,
+        GameResult::Loss => 0,
+    };
+
+    hand_score + result_score
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<(Hand, Column)>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines(input, parse_round)
+    }
+
+    fn part1(guide: &Self::Input) -> Answer {
+        part1(guide).into()
+    }
+
+    fn part2(guide: &Self::Input) -> Answer {
+        part2(guide).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_reports_position() {
+        let err = Day2::parse("A Y\nB X\nC Q\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 3: invalid strategy column: \"Q\""
+        );
+    }
+}
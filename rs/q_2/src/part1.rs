@@ -1,4 +1,4 @@
-use crate::{Hand, GameResult, parse_opponent, calculate_score};
+use crate::{calculate_score, Column, GameResult, Hand};
 
 #[derive(Debug)]
 struct Round {
@@ -7,16 +7,16 @@ struct Round {
 }
 
 impl Round {
-    pub fn from_str(input: &str) -> Self {
-        let parts = input.split(' ').collect::<Vec<&str>>();
-        let opponent = parse_opponent(parts[0]);
-        let player = match parts[1] {
-            "X" => Hand::Rock,
-            "Y" => Hand::Paper,
-            "Z" => Hand::Scissors,
-            _ => panic!("Invalid player hand"),
+    pub fn new(opponent: &Hand, column: &Column) -> Self {
+        let player = match column {
+            Column::X => Hand::Rock,
+            Column::Y => Hand::Paper,
+            Column::Z => Hand::Scissors,
         };
-        Round { opponent, player }
+        Round {
+            opponent: opponent.clone(),
+            player,
+        }
     }
 
     fn get_result(&self) -> GameResult {
@@ -38,10 +38,9 @@ impl Round {
     }
 }
 
-pub fn part1(input: &str) {
-    let points: u32 = input.lines()
-        .map(|line| Round::from_str(line).get_points())
-        .sum();
-
-    println!("{}", points);
+pub fn part1(guide: &[(Hand, Column)]) -> u32 {
+    guide
+        .iter()
+        .map(|(opponent, column)| Round::new(opponent, column).get_points())
+        .sum()
 }
@@ -1,6 +1,4 @@
-use core::panic;
-
-use crate::{parse_opponent, GameResult, Hand, calculate_score};
+use crate::{calculate_score, Column, GameResult, Hand};
 
 #[derive(Debug)]
 struct Round {
@@ -8,21 +6,19 @@ struct Round {
     result: GameResult,
 }
 
-fn parse_result(input: &str) -> GameResult {
-    match input {
-        "X" => GameResult::Loss,
-        "Y" => GameResult::Draw,
-        "Z" => GameResult::Win,
-        _ => panic!("Invalid game result"),
+fn parse_result(column: &Column) -> GameResult {
+    match column {
+        Column::X => GameResult::Loss,
+        Column::Y => GameResult::Draw,
+        Column::Z => GameResult::Win,
     }
 }
 
 impl Round {
-    pub fn from_str(input: &str) -> Self {
-        let parts: Vec<&str> = input.split(' ').collect();
+    pub fn new(opponent: &Hand, column: &Column) -> Self {
         Self {
-            opponent: parse_opponent(parts[0]),
-            result: parse_result(parts[1]),
+            opponent: opponent.clone(),
+            result: parse_result(column),
         }
     }
 
@@ -39,10 +35,9 @@ impl Round {
     }
 }
 
-pub fn part2(input: &str) {
-    let points: u32 = input.lines()
-        .map(|line| Round::from_str(line).get_points())
-        .sum();
-
-    println!("{}", points);
+pub fn part2(guide: &[(Hand, Column)]) -> u32 {
+    guide
+        .iter()
+        .map(|(opponent, column)| Round::new(opponent, column).get_points())
+        .sum()
 }
@@ -0,0 +1,25 @@
+use aoc_common::{Answer, Result, Solution};
+
+use part1::{parse, part1, Jobs};
+use part2::part2;
+
+mod part1;
+mod part2;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Jobs;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(jobs: &Self::Input) -> Answer {
+        part1(jobs).into()
+    }
+
+    fn part2(jobs: &Self::Input) -> Answer {
+        part2(jobs).map_or(Answer::NoSolution, Answer::from)
+    }
+}
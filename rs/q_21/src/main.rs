@@ -1,10 +1,8 @@
-use part1::part1;
-use part2::part2;
-
-mod part1;
-mod part2;
+use aoc_common::{load_parsed, Solution};
+use q_21::Day21;
 
 fn main() {
-    part1();
-    part2();
+    let jobs = load_parsed::<Day21>(env!("CARGO_MANIFEST_DIR"));
+    println!("Part 1 result: {}", Day21::part1(&jobs));
+    println!("Part 2 result: {}", Day21::part2(&jobs));
 }
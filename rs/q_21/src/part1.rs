@@ -1,26 +1,35 @@
-use std::{collections::HashMap, fs::read_to_string};
+use std::collections::{HashMap, HashSet};
 
+use aoc_common::{parse_lines, ParseError, Result};
 use Operator::{Add, Div, Mul, Sub};
 
 #[derive(Debug)]
-struct Operation {
-    lhs: String,
-    rhs: String,
-    operator: Operator,
+pub struct Operation {
+    pub lhs: String,
+    pub rhs: String,
+    pub operator: Operator,
 }
 
 impl Operation {
-    pub fn new(input: &str) -> Self {
-        Self {
-            lhs: input[0..4].to_owned(),
-            rhs: input[7..].to_owned(),
-            operator: Operator::new(input.chars().nth(5).unwrap()),
+    pub fn new(input: &str) -> Result<Self> {
+        let mut parts = input.split(' ');
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(lhs), Some(operator), Some(rhs), None) => Ok(Self {
+                lhs: lhs.to_owned(),
+                rhs: rhs.to_owned(),
+                operator: Operator::new(operator)
+                    .ok_or_else(|| ParseError::new("invalid operator").locate(input, operator))?,
+            }),
+            _ => Err(
+                ParseError::new("expected a number or `<monkey> <operator> <monkey>`")
+                    .with_text(input),
+            ),
         }
     }
 }
 
 #[derive(Debug)]
-enum Operator {
+pub enum Operator {
     Add,
     Sub,
     Mul,
@@ -28,24 +37,26 @@ enum Operator {
 }
 
 impl Operator {
-    pub fn new(ch: char) -> Self {
-        match ch {
-            '+' => Add,
-            '-' => Sub,
-            '*' => Mul,
-            '/' => Div,
-            _ => panic!("Invalid operator"),
+    pub fn new(input: &str) -> Option<Self> {
+        match input {
+            "+" => Some(Add),
+            "-" => Some(Sub),
+            "*" => Some(Mul),
+            "/" => Some(Div),
+            _ => None,
         }
     }
 }
 
 #[derive(Debug)]
-enum Expression {
+pub enum Expression {
     Literal(u64),
     Operation(Operation),
 }
 
-fn eval(name: &str, map: &HashMap<String, Expression>) -> u64 {
+pub type Jobs = HashMap<String, Expression>;
+
+fn eval(name: &str, map: &Jobs) -> u64 {
     let value = map.get(name).unwrap();
     match value {
         Expression::Literal(v) => *v,
@@ -58,27 +69,46 @@ fn eval(name: &str, map: &HashMap<String, Expression>) -> u64 {
     }
 }
 
-fn parse(input: &str) -> HashMap<String, Expression> {
-    let mut map = HashMap::new();
-
-    input.lines().for_each(|line| {
-        let mut iter = line.split(": ");
-        let name = iter.next().unwrap();
-        let value = iter.next().unwrap();
+pub fn parse(input: &str) -> Result<Jobs> {
+    let jobs = parse_lines(input, |line| {
+        let (name, value) = line
+            .split_once(": ")
+            .ok_or_else(|| ParseError::new("expected `<monkey>: <job>`").with_text(line))?;
         let value = if let Ok(number) = value.parse::<u64>() {
             Expression::Literal(number)
         } else {
-            Expression::Operation(Operation::new(value))
+            Expression::Operation(Operation::new(value).map_err(|err| err.locate(line, value))?)
         };
-        map.insert(name.to_owned(), value);
-    });
 
-    map
-}
+        Ok((name.to_owned(), value))
+    })?;
 
-pub fn part1() {
-    let input = read_to_string("./data/input.txt").unwrap();
-    let map = parse(&input);
+    let names = jobs
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<HashSet<_>>();
+
+    for (i, (_, job)) in jobs.iter().enumerate() {
+        if let Expression::Operation(operation) = job {
+            for operand in [&operation.lhs, &operation.rhs] {
+                if !names.contains(operand.as_str()) {
+                    return Err(ParseError::new("no monkey has this name")
+                        .with_text(operand)
+                        .on_line(i + 1));
+                }
+            }
+        }
+    }
+
+    let map = jobs.into_iter().collect::<Jobs>();
+
+    if !map.contains_key("root") {
+        return Err(ParseError::new("there is no `root` monkey"));
+    }
+
+    Ok(map)
+}
 
-    println!("Part 1 result: {}", eval("root", &map));
+pub fn part1(jobs: &Jobs) -> u64 {
+    eval("root", jobs)
 }
@@ -1,9 +1,9 @@
 // Original code by: JuniorBirdman1115 on reddit https://www.reddit.com/user/JuniorBirdman1115/
 // From this comment: https://www.reddit.com/r/adventofcode/comments/zrav4h/comment/j1bymnm/
 // Refactored to remove some duplication and fixed cargo clippy warnings
-use regex::Regex;
 use std::collections::HashMap;
-use std::fs::read_to_string;
+
+use crate::part1::{Expression as Job, Jobs, Operator};
 
 #[derive(Debug)]
 pub enum Expression {
@@ -20,58 +20,42 @@ use Expression::*;
 
 pub type SymbolTable = HashMap<String, Expression>;
 
-fn read_input(filename: &str) -> String {
-    read_to_string(filename)
-        .unwrap_or_else(|error| panic!("read_input(): error reading {}: {:?}", filename, error))
-}
-
-fn parse_input(contents: &str) -> SymbolTable {
-    let int_re = Regex::new(r"([a-z]+): (\d+)").unwrap();
-    let exp_re = Regex::new(r"([a-z]+): ([a-z]+) (\+|\-|\*|/) ([a-z]+)").unwrap();
-    let mut symtab = SymbolTable::new();
-    contents.lines().enumerate().for_each(|(i, line)| {
-        if int_re.is_match(line) {
-            let caps = int_re.captures(line).unwrap();
-            let name = caps[1].to_string();
-            let val = caps[2].parse::<u64>().unwrap();
-            symtab.insert(name, Expression::Integer(val));
-        } else if exp_re.is_match(line) {
-            let caps = exp_re.captures(line).unwrap();
-            let name = caps[1].to_string();
-            let operand1 = caps[2].to_string();
-            let operand2 = caps[4].to_string();
-            let op = &caps[3];
-            match op {
-                "+" => symtab.insert(name, Plus(operand1, operand2)),
-                "-" => symtab.insert(name, Minus(operand1, operand2)),
-                "*" => symtab.insert(name, Times(operand1, operand2)),
-                "/" => symtab.insert(name, Divide(operand1, operand2)),
-                _ => unreachable!("Unreachable code in parse_input()"),
+fn build_symtab(jobs: &Jobs) -> SymbolTable {
+    jobs.iter()
+        .map(|(name, job)| {
+            let exp = match job {
+                Job::Literal(val) => Integer(*val),
+                Job::Operation(operation) => {
+                    let operand1 = operation.lhs.clone();
+                    let operand2 = operation.rhs.clone();
+                    match operation.operator {
+                        Operator::Add => Plus(operand1, operand2),
+                        Operator::Sub => Minus(operand1, operand2),
+                        Operator::Mul => Times(operand1, operand2),
+                        Operator::Div => Divide(operand1, operand2),
+                    }
+                }
             };
-        } else {
-            panic!("parse_input(): parse error at line {}: '{}'", i + 1, line);
-        }
-    });
 
-    symtab
+            (name.clone(), exp)
+        })
+        .collect()
 }
 
-fn modify(symtab: &mut SymbolTable) {
+/// `None` if `root` only yells a number, so there are no two sides to compare.
+fn modify(symtab: &mut SymbolTable) -> Option<()> {
     let root = String::from("root");
     let root_exp = symtab.get(&root).unwrap();
     match root_exp {
-        Plus(op1, op2)
-        | Minus(op1, op2)
-        | Times(op1, op2)
-        | Divide(op1, op2) => {
+        Plus(op1, op2) | Minus(op1, op2) | Times(op1, op2) | Divide(op1, op2) => {
             let new_exp = Equals(op1.clone(), op2.clone());
             symtab.insert(root, new_exp);
         }
-        _ => {
-            panic!("modify(): bug - expected +, -, *, or / in root node!");
-        }
+        _ => return None,
     }
     symtab.insert("humn".to_owned(), Unknown);
+
+    Some(())
 }
 
 fn eval(name: &str, symtab: &SymbolTable) -> u64 {
@@ -152,10 +136,8 @@ fn solve(name: &str, symtab: &SymbolTable) -> u64 {
     }
 }
 
-pub fn part2() {
-    let contents = read_input("./data/input.txt");
-    let mut symtab = parse_input(&contents);
-    modify(&mut symtab);
-    let result = solve("root", &symtab);
-    println!("Part 2 result: {}", result);
+pub fn part2(jobs: &Jobs) -> Option<u64> {
+    let mut symtab = build_symtab(jobs);
+    modify(&mut symtab)?;
+    Some(solve("root", &symtab))
 }
@@ -1,5 +1,7 @@
 use std::{collections::BTreeMap, ops::RangeInclusive};
 
+use aoc_common::{parse_number, ParseError, Result};
+
 use Direction::*;
 use Instruction::*;
 use Orientation::*;
@@ -22,59 +24,59 @@ pub enum Tile {
     Void,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Instruction {
     Move(usize),
     Turn(Direction),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Direction {
     Counterclockwise,
     Clockwise,
 }
 
 impl Tile {
-    pub fn from_char(ch: char) -> Self {
+    pub fn from_char(ch: char) -> Result<Self> {
         match ch {
-            ' ' => Void,
-            '.' => Open,
-            '#' => Wall,
-            _ => panic!("Invalid tile char."),
+            ' ' => Ok(Void),
+            '.' => Ok(Open),
+            '#' => Ok(Wall),
+            _ => Err(ParseError::new("invalid tile").with_text(ch)),
         }
     }
 }
 
-pub fn parse_instructions(input: &str) -> Vec<Instruction> {
+pub fn parse_instructions(input: &str) -> Result<Vec<Instruction>> {
     let mut instructions = vec![];
-    let mut number_buffer = String::new();
+    let mut number_start = None;
 
-    let mut iter = input.chars().peekable();
-    while let Some(ch) = iter.next() {
+    let mut iter = input.char_indices().peekable();
+    while let Some((i, ch)) = iter.next() {
         if ch.is_ascii_digit() {
-            number_buffer.push(ch);
-            if let Some(next_ch) = iter.peek() {
-                if !next_ch.is_ascii_digit() {
-                    let number = number_buffer.parse::<usize>().unwrap();
-                    instructions.push(Move(number));
-                    number_buffer = String::new();
-                }
-            } else {
-                let number = number_buffer.parse::<usize>().unwrap();
-                instructions.push(Move(number));
-                number_buffer = String::new();
+            let start = *number_start.get_or_insert(i);
+            if !matches!(iter.peek(), Some((_, next_ch)) if next_ch.is_ascii_digit()) {
+                let number = &input[start..=i];
+                instructions.push(Move(
+                    parse_number(number).map_err(|err| err.locate(input, number))?,
+                ));
+                number_start = None;
             }
         } else if ch == 'R' {
             instructions.push(Turn(Clockwise));
-        } else {
+        } else if ch == 'L' {
             instructions.push(Turn(Counterclockwise));
+        } else {
+            return Err(
+                ParseError::new("invalid instruction").locate(input, &input[i..i + ch.len_utf8()])
+            );
         }
     }
 
-    instructions
+    Ok(instructions)
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Board {
     tiles: BTreeMap<Coord, Tile>,
     start: (usize, usize),
@@ -85,7 +87,7 @@ pub struct Board {
 }
 
 impl Board {
-    pub fn from_str(input: &str) -> Self {
+    pub fn from_str(input: &str) -> Result<Self> {
         let mut res = Self {
             tiles: BTreeMap::new(),
             x_range: 0..=usize::MAX,
@@ -99,9 +101,10 @@ impl Board {
         let mut max_y = 0;
         let mut start = false;
 
-        input.lines().enumerate().for_each(|(y, line)| {
-            line.chars().enumerate().for_each(|(x, ch)| {
-                let tile = Tile::from_char(ch);
+        for (y, line) in input.lines().enumerate() {
+            for (x, ch) in line.chars().enumerate() {
+                let tile =
+                    Tile::from_char(ch).map_err(|err| err.at_column(x + 1).on_line(y + 1))?;
                 match tile {
                     Open | Wall => {
                         if !start && y == 0 {
@@ -121,23 +124,27 @@ impl Board {
                 }
 
                 if let Void = &tile {
-                    return;
+                    continue;
                 }
 
                 res.tiles.insert((y, x), tile);
-            })
-        });
+            }
+        }
+
+        if !start {
+            return Err(ParseError::new("the first row of the board has no tiles").on_line(1));
+        }
 
         res.x_range = 0..=max_x;
         res.y_range = 0..=max_y;
-        res
+        Ok(res)
     }
 
     pub fn walk(&mut self, steps: usize) {
-        let iter = BoardIterator::new(self, steps).enumerate();
+        let iter = BoardIterator::new(self, steps);
         let mut current_pos = self.position;
 
-        for (_, (pos, tile)) in iter {
+        for (pos, tile) in iter {
             match tile {
                 Open => {
                     current_pos = pos;
@@ -173,11 +180,7 @@ impl Board {
     }
 
     pub fn get(&self, pos: &Coord) -> Tile {
-        self.tiles
-            .get(pos)
-            .map(|t| *t)
-            .or_else(|| Some(Tile::Void))
-            .unwrap()
+        self.tiles.get(pos).copied().unwrap_or(Tile::Void)
     }
 
     pub fn first_non_void(
@@ -268,11 +271,7 @@ impl<'a> Iterator for BoardIterator<'a> {
     type Item = ((usize, usize), Tile);
 
     fn next(&mut self) -> Option<Self::Item> {
-        let next = self.range_iter.next();
-        if let None = next {
-            return None;
-        }
-        let i = next.unwrap();
+        let i = self.range_iter.next()?;
         self.current += 1;
 
         let pos = match self.board.orientation {
@@ -0,0 +1,56 @@
+#[macro_use]
+extern crate lazy_static;
+
+use aoc_common::{Answer, ParseError, Result, Solution};
+use board::{parse_instructions, Board, Instruction};
+use part1::part1;
+
+pub use part2::part2;
+
+mod board;
+mod part1;
+mod part2;
+
+/// The monkeys' notes: the board and the path to follow on it.
+pub struct Notes {
+    board: Board,
+    instructions: Vec<Instruction>,
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Notes;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (board, instructions) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::new("missing blank line between board and path"))?;
+
+        Ok(Notes {
+            board: Board::from_str(board)?,
+            instructions: parse_instructions(instructions.trim_end())
+                .map_err(|err| err.on_line(board.lines().count() + 2))?,
+        })
+    }
+
+    fn part1(notes: &Self::Input) -> Answer {
+        part1(notes).into()
+    }
+
+    fn part2(_: &Self::Input) -> Answer {
+        // part 2 (folding the board into a cube) is still a work in progress
+        Answer::Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let notes = Day22::parse(include_str!("../data/demo.txt")).unwrap();
+        assert_eq!(part1(&notes), 6032);
+    }
+}
@@ -1,18 +1,8 @@
-#[macro_use]
-extern crate lazy_static;
-
-// use part1::part1;
-
-use std::fs::read_to_string;
-
-use part2::part2;
-
-// mod board;
-// mod part1;
-mod part2;
+use aoc_common::{load_parsed, Solution};
+use q_22::Day22;
 
 fn main() {
-    // part1();
-    let input = read_to_string("./data/demo.txt").unwrap();
-    part2(&input);
+    let notes = load_parsed::<Day22>(env!("CARGO_MANIFEST_DIR"));
+    println!("part 1: {}", Day22::part1(&notes));
+    println!("part 2: {}", Day22::part2(&notes));
 }
@@ -1,7 +1,9 @@
 use std::collections::HashMap;
 
+use aoc_common::Grid;
+
 pub struct Face {
-    pub grid: Vec<Vec<char>>,
+    pub grid: Grid<char>,
     pub identity: FaceIdentity,
 }
 
@@ -1,14 +1,16 @@
 use std::collections::{HashMap, HashSet};
 
-use super::cube::{FaceIdentity::*, AxisDirection};
-use super::cube::{Cube, Face, FaceIdentity};
+use aoc_common::Grid;
+
 use super::cube::AxisDirection::*;
+use super::cube::{AxisDirection, FaceIdentity::*};
+use super::cube::{Cube, Face, FaceIdentity};
 
-fn transform(face_size: usize, grid: &Vec<Vec<char>>) -> Cube {
+fn transform(face_size: usize, grid: &Grid<char>) -> Cube {
     let mut faces: HashMap<FaceIdentity, Face> = HashMap::new();
     let mut face_coords = vec![];
 
-    for (y, row) in grid.iter().step_by(face_size).enumerate() {
+    for (y, row) in grid.rows().step_by(face_size).enumerate() {
         for (x, ch) in row.iter().step_by(face_size).enumerate() {
             if *ch == ' ' {
                 continue;
@@ -40,7 +42,7 @@ fn transform(face_size: usize, grid: &Vec<Vec<char>>) -> Cube {
                 break;
             }
 
-            for (visited_coord, (ident, rots)) in visited.iter() {
+            for visited_coord in visited.keys() {
                 if are_neighbours(candidate, visited_coord) {
                     let new_visited = (Back, 0);
                     visited.insert(candidate, new_visited);
@@ -61,18 +63,16 @@ fn are_neighbours(pos: &(usize, usize), pos2: &(usize, usize)) -> bool {
     delta_x + delta_y == 1
 }
 
-fn get_grid_range(pos: &(usize, usize), face_size: usize, grid: &Vec<Vec<char>>) -> Vec<Vec<char>> {
-    let mut res = Vec::with_capacity(face_size);
+fn get_grid_range(pos: &(usize, usize), face_size: usize, grid: &Grid<char>) -> Grid<char> {
+    let cells = grid
+        .rows()
+        .skip(pos.1 * face_size)
+        .take(face_size)
+        .flat_map(|row| row.iter().skip(pos.0 * face_size).take(face_size))
+        .copied()
+        .collect();
 
-    for y in pos.1 * face_size..(pos.1 + 1) * face_size {
-        let mut row = Vec::with_capacity(face_size);
-        for x in pos.0 * face_size..(pos.0 + 1) * face_size {
-            row.push(grid[y][x]);
-        }
-        res.push(row);
-    }
-
-    res
+    Grid::from_vec(face_size, cells)
 }
 
 // assumes pos and pos2 are neighbours
@@ -125,37 +125,32 @@ mod tests {
         let input = include_str!("./../../data/demo.txt");
         let mut iter = input.split("\n\n");
         let grid_lines = iter.next().unwrap();
-        let (_, grid) = parse_grid(grid_lines);
+        let grid = parse_grid(grid_lines).unwrap();
         let sub_grid = get_grid_range(&(1, 1), 4, &grid);
-        let expected = vec![
-            vec!['.', '.', '.', '.'],
-            vec!['.', '.', '.', '.'],
-            vec!['.', '.', '.', '#'],
-            vec!['.', '.', '.', '.'],
-        ];
-
-        assert_eq!(sub_grid, expected);
+        let expected = "....\n....\n...#\n....";
+
+        assert_eq!(sub_grid.to_string(), expected);
     }
 
     #[test]
     fn are_neighbours_works() {
-        assert_eq!(are_neighbours(&(0,1), &(1,0)), false);
-        assert_eq!(are_neighbours(&(0,2), &(2,0)), false);
-        assert_eq!(are_neighbours(&(0,1), &(0,3)), false);
-        assert_eq!(are_neighbours(&(0,0), &(0,0)), false);
-        assert_eq!(are_neighbours(&(1,1), &(1,0)), true);
-        assert_eq!(are_neighbours(&(2,1), &(2,0)), true);
-        assert_eq!(are_neighbours(&(1,2), &(1,3)), true);
-        assert_eq!(are_neighbours(&(4,5), &(5,5)), true);
+        assert!(!are_neighbours(&(0, 1), &(1, 0)));
+        assert!(!are_neighbours(&(0, 2), &(2, 0)));
+        assert!(!are_neighbours(&(0, 1), &(0, 3)));
+        assert!(!are_neighbours(&(0, 0), &(0, 0)));
+        assert!(are_neighbours(&(1, 1), &(1, 0)));
+        assert!(are_neighbours(&(2, 1), &(2, 0)));
+        assert!(are_neighbours(&(1, 2), &(1, 3)));
+        assert!(are_neighbours(&(4, 5), &(5, 5)));
     }
 
     #[test]
     fn determine_direction_works() {
-        assert_eq!(determine_direction(&(1,1), &(2,1)).unwrap(), XPositive);
-        assert_eq!(determine_direction(&(1,1), &(0,1)).unwrap(), XNegative);
-        assert_eq!(determine_direction(&(1,1), &(1,2)).unwrap(), YPositive);
-        assert_eq!(determine_direction(&(1,1), &(1,0)).unwrap(), YNegative);
-        assert!(determine_direction(&(1,1), &(1,1)).is_none());
+        assert_eq!(determine_direction(&(1, 1), &(2, 1)).unwrap(), XPositive);
+        assert_eq!(determine_direction(&(1, 1), &(0, 1)).unwrap(), XNegative);
+        assert_eq!(determine_direction(&(1, 1), &(1, 2)).unwrap(), YPositive);
+        assert_eq!(determine_direction(&(1, 1), &(1, 0)).unwrap(), YNegative);
+        assert!(determine_direction(&(1, 1), &(1, 1)).is_none());
     }
 
     #[test]
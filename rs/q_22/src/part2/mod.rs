@@ -1,4 +1,10 @@
-use crate::part2::parse::{parse_grid, print_grid, pad_grid};
+// Folding the board into a cube is unfinished, so most of the helpers below
+// aren't wired into `part2` yet.
+#![allow(dead_code)]
+
+use aoc_common::Result;
+
+use crate::part2::parse::parse_grid;
 
 mod cube;
 mod grid_to_cube;
@@ -6,12 +12,12 @@ mod instruction;
 mod parse;
 mod transition;
 
-pub fn part2(input: &str) {
+pub fn part2(input: &str) -> Result<()> {
     let mut iter = input.split("\n\n");
     let grid_lines = iter.next().unwrap();
     // let mut instructions = iter.next().unwrap();
-    let (max_len, mut grid) = parse_grid(grid_lines);
-    pad_grid(max_len, &mut grid);
-    let grid_str = print_grid(&grid);
-    println!("{}", grid_str);
+    let grid = parse_grid(grid_lines)?;
+    println!("{}", grid);
+
+    Ok(())
 }
@@ -1,78 +1,41 @@
-use super::instruction::*;
+use aoc_common::{parse_number, Grid, ParseError, Result};
+
 use super::instruction::Direction::*;
 use super::instruction::Instruction::*;
+use super::instruction::*;
 
-pub fn parse_grid(input: &str) -> (usize, Vec<Vec<char>>) {
-    let mut res = vec![];
-    let mut max_len = 0;
-
-    for line in input.lines() {
-        if line.len() > max_len {
-            max_len = line.len();
-        }
-        let mut row = vec![];
-
-        for ch in line.chars() {
-            row.push(ch);
-        }
-
-        res.push(row);
-    }
-
-    (max_len, res)
-}
-
-pub fn pad_grid(max_len: usize, grid: &mut Vec<Vec<char>>) {
-    for row in grid.iter_mut() {
-        while row.len() < max_len {
-            row.push(' ');
-        }
-    }
-}
-
-pub fn print_grid(grid: &Vec<Vec<char>>) -> String {
-    let mut res = String::new();
-
-    for row in grid.iter() {
-        for ch in row.iter() {
-            res.push(*ch);
-        }
-
-        res.push('\n');
-    }
-
-    res.pop();
-
-    res
+// the board's rows stop at their last tile, pad them so the grid is rectangular
+pub fn parse_grid(input: &str) -> Result<Grid<char>> {
+    Grid::from_chars_padded(input, ' ', |_, ch| Ok(ch))
 }
 
-pub fn parse_instructions(input: &str) -> Vec<Instruction> {
+pub fn parse_instructions(input: &str) -> Result<Vec<Instruction>> {
     let mut instructions = vec![];
-    let mut number_buffer = String::new();
+    let mut number_start = None;
 
-    let mut iter = input.chars().peekable();
-    while let Some(ch) = iter.next() {
+    let mut iter = input.char_indices().peekable();
+    while let Some((i, ch)) = iter.next() {
         if ch.is_ascii_digit() {
-            number_buffer.push(ch);
-            if let Some(next_ch) = iter.peek() {
-                if !next_ch.is_ascii_digit() {
-                    let number = number_buffer.parse::<usize>().unwrap();
-                    instructions.push(Move(number));
-                    number_buffer = String::new();
-                }
-            } else {
-                let number = number_buffer.parse::<usize>().unwrap();
-                instructions.push(Move(number));
-                number_buffer = String::new();
+            let start = *number_start.get_or_insert(i);
+            if !matches!(iter.peek(), Some((_, next_ch)) if next_ch.is_ascii_digit()) {
+                let number = &input[start..=i];
+                instructions.push(Move(
+                    parse_number(number).map_err(|err| err.locate(input, number))?,
+                ));
+                number_start = None;
             }
         } else if ch == 'R' {
             instructions.push(Turn(Clockwise));
-        } else {
+        } else if ch == 'L' {
             instructions.push(Turn(Counterclockwise));
+        } else {
+            return Err(
+                ParseError::new("invalid instruction").locate(input, &input[i..i + ch.len_utf8()])
+            );
         }
     }
 
-    instructions
+    Ok(instructions)
 }
 
 #[cfg(test)]
@@ -85,10 +48,8 @@ mod tests {
         let input = include_str!("./../../data/demo.txt");
         let mut iter = input.split("\n\n");
         let grid_lines = iter.next().unwrap();
-        let (max_len, mut grid) = parse_grid(grid_lines);
-        pad_grid(max_len, &mut grid);
-        let grid_str = print_grid(&grid);
-        assert_eq!(grid_str, expected);
+        let grid = parse_grid(grid_lines).unwrap();
+        assert_eq!(grid.to_string(), expected);
     }
 
     #[test]
@@ -96,7 +57,7 @@ mod tests {
         let input = include_str!("./../../data/demo.txt");
         let mut iter = input.split("\n\n");
         let instruction_input = iter.nth(1).unwrap();
-        let instructions = parse_instructions(instruction_input);
+        let instructions = parse_instructions(instruction_input.trim_end()).unwrap();
         let expected = vec![
             Move(10),
             Turn(Clockwise),
@@ -113,5 +74,8 @@ mod tests {
             Move(5),
         ];
         assert_eq!(instructions, expected);
+
+        let err = parse_instructions("10R5X").unwrap_err();
+        assert_eq!(err.column(), Some(5));
     }
 }
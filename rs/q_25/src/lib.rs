@@ -0,0 +1,70 @@
+use aoc_common::{parse_lines, Answer, ParseError, Result, Solution};
+use convert::number_to_snafu;
+use parse::parse_number;
+
+mod convert;
+mod parse;
+
+fn part1(numbers: &[isize]) -> String {
+    let sum = numbers.iter().sum();
+    number_to_snafu(sum)
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Vec<isize>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines(input, |line| {
+            parse_number(line)
+                .ok_or_else(|| ParseError::new("invalid SNAFU number").with_text(line))
+        })
+    }
+
+    fn part1(numbers: &Self::Input) -> Answer {
+        part1(numbers).into()
+    }
+
+    fn part2(_: &Self::Input) -> Answer {
+        // day 25 only has a single puzzle
+        Answer::Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    const DEMO: &str = "1=-0-2
+12111
+2=0=
+21
+2=01
+111
+20012
+112
+1=-1=
+1-12
+12
+1=
+122";
+
+    use std::fs::read_to_string;
+
+    use super::*;
+
+    fn sum_snafu(input: &str) -> isize {
+        input.lines().map(|line| parse_number(line).unwrap()).sum()
+    }
+
+    #[test]
+    fn sum_snafu_works() {
+        assert_eq!(sum_snafu(DEMO), 4890);
+    }
+
+    #[test]
+    fn part1_works() {
+        let input = read_to_string("./data/demo.txt").unwrap();
+        let res = part1(&Day25::parse(&input).unwrap());
+        assert_eq!(res, "2=-1=0");
+    }
+}
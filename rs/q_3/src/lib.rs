@@ -0,0 +1,71 @@
+use aoc_common::{parse_lines, Answer, ParseError, Result, Solution};
+
+fn get_item_priority(letter: char) -> u8 {
+    if letter.is_ascii_lowercase() {
+        return letter as u8 - b'a' + 1;
+    }
+
+    if letter.is_ascii_uppercase() {
+        return letter as u8 - b'A' + 27;
+    }
+
+    panic!("Invalid character");
+}
+
+fn find_common_letter(str1: &str, str2: &str) -> Option<char> {
+    str1.chars().find(|&ch| str2.contains(ch))
+}
+
+fn part1(lines: &[String]) -> u32 {
+    lines
+        .iter()
+        .map(|line| {
+            let half = line.len() / 2;
+            (&line[0..half], &line[half..])
+        })
+        .map(|parts| find_common_letter(parts.0, parts.1).unwrap())
+        .map(|ch| get_item_priority(ch) as u32)
+        .sum()
+}
+
+fn find_common_letter_in_group(group: &[String]) -> Option<char> {
+    group[0]
+        .chars()
+        .find(|&ch| group[1].contains(ch) && group[2].contains(ch))
+}
+
+fn part2(lines: &[String]) -> u32 {
+    lines
+        .chunks(3)
+        .map(|chunk| find_common_letter_in_group(chunk).unwrap())
+        .map(|ch| get_item_priority(ch) as u32)
+        .sum()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines(input, |line| {
+            if let Some(item) = line.matches(|ch: char| !ch.is_ascii_alphabetic()).next() {
+                return Err(ParseError::new("invalid item").locate(line, item));
+            }
+
+            if line.len() % 2 == 1 {
+                return Err(ParseError::new("number of items not divisible by 2").with_text(line));
+            }
+
+            Ok(line.to_owned())
+        })
+    }
+
+    fn part1(lines: &Self::Input) -> Answer {
+        part1(lines).into()
+    }
+
+    fn part2(lines: &Self::Input) -> Answer {
+        part2(lines).into()
+    }
+}
@@ -0,0 +1,91 @@
+use std::ops::Range;
+
+use aoc_common::{parse_lines, parse_number, Answer, IntervalSet, ParseError, Result, Solution};
+
+/// The first and last section IDs of an elf, both included.
+#[derive(Debug)]
+pub struct Section(u32, u32);
+
+impl Section {
+    pub fn parse(input: &str) -> Result<Self> {
+        let (start, end) = input
+            .split_once('-')
+            .ok_or_else(|| ParseError::new("expected a range of sections").with_text(input))?;
+        let parse_bound = |bound| parse_number(bound).map_err(|err| err.locate(input, bound));
+
+        Ok(Self(parse_bound(start)?, parse_bound(end)?))
+    }
+
+    // widened so that the range past a last section of `u32::MAX` doesn't overflow
+    fn range(&self) -> Range<u64> {
+        u64::from(self.0)..u64::from(self.1) + 1
+    }
+
+    pub fn contains(&self, other: &Section) -> bool {
+        IntervalSet::from(self.range()).contains_range(&other.range())
+    }
+
+    pub fn overlaps(&self, other: &Section) -> bool {
+        IntervalSet::from(self.range()).overlaps(&other.range())
+    }
+}
+
+fn parse_group(line: &str) -> Result<(Section, Section)> {
+    let (first, second) = line
+        .split_once(',')
+        .ok_or_else(|| ParseError::new("expected a pair of elves").with_text(line))?;
+    let parse_section = |section| Section::parse(section).map_err(|err| err.locate(line, section));
+
+    Ok((parse_section(first)?, parse_section(second)?))
+}
+
+fn count_groups<F>(groups: &[(Section, Section)], compare: F) -> u32
+where
+    F: Fn(&Section, &Section) -> bool,
+{
+    let mut count: u32 = 0;
+
+    for group in groups {
+        if compare(&group.0, &group.1) {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<(Section, Section)>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_lines(input, parse_group)
+    }
+
+    fn part1(groups: &Self::Input) -> Answer {
+        count_groups(groups, |section1, section2| {
+            section1.contains(section2) || section2.contains(section1)
+        })
+        .into()
+    }
+
+    fn part2(groups: &Self::Input) -> Answer {
+        count_groups(groups, |section1, section2| section1.overlaps(section2)).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn section_works() {
+        let (first, second) = parse_group("2-4,4294967295-4294967295").unwrap();
+        assert!(!first.overlaps(&second));
+
+        let (first, second) = parse_group("3-4294967295,4294967295-4294967295").unwrap();
+        assert!(first.contains(&second) && first.overlaps(&second));
+        assert!(!second.contains(&first));
+    }
+}
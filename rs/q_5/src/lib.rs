@@ -0,0 +1,159 @@
+use aoc_common::{parse_lines, parse_number, Answer, ParseError, Result, Solution};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+#[derive(Debug)]
+struct Move {
+    pub amount: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+impl Move {
+    pub fn from_str(input: &str) -> Result<Self> {
+        lazy_static! {
+            static ref RE: Regex = Regex::new(r"^move (\d+) from (\d+) to (\d+)$").unwrap();
+        }
+
+        let captures = RE.captures(input).ok_or_else(|| {
+            ParseError::new("expected `move <amount> from <stack> to <stack>`").with_text(input)
+        })?;
+        let get_capture = |i| {
+            let capture = captures.get(i).unwrap().as_str();
+            parse_number::<usize>(capture).map_err(|err| err.locate(input, capture))
+        };
+        let get_stack = |i| {
+            get_capture(i)?.checked_sub(1).ok_or_else(|| {
+                ParseError::new("stacks are numbered from 1").locate(input, &captures[i])
+            })
+        };
+
+        Ok(Self {
+            amount: get_capture(1)?,
+            from: get_stack(2)?,
+            to: get_stack(3)?,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Procedure {
+    stacks: Vec<Vec<char>>,
+    moves: Vec<Move>,
+}
+
+fn parse_file(input: &str) -> Result<Procedure> {
+    let (drawing, moves) = input.split_once("\n\n").ok_or_else(|| {
+        ParseError::new("expected the drawing and the moves to be separated by an empty line")
+    })?;
+
+    let stacks = parse_stacks(drawing);
+    let moves =
+        parse_moves(moves, stacks.len()).map_err(|err| err.on_line(drawing.lines().count() + 2))?;
+
+    Ok(Procedure { stacks, moves })
+}
+
+fn parse_moves(input: &str, stack_count: usize) -> Result<Vec<Move>> {
+    parse_lines(input, |line| {
+        let mv = Move::from_str(line)?;
+        if mv.from >= stack_count || mv.to >= stack_count {
+            return Err(
+                ParseError::new(format!("there are only {} stacks", stack_count)).with_text(line),
+            );
+        }
+
+        Ok(mv)
+    })
+}
+
+fn init_until(n: usize, stacks: &mut Vec<Vec<char>>) {
+    for _ in stacks.len()..=n {
+        stacks.push(Vec::new());
+    }
+}
+
+fn parse_stacks(input: &str) -> Vec<Vec<char>> {
+    let mut stacks = Vec::new();
+    let mut iter = input.lines().peekable();
+
+    while let Some(line) = iter.next() {
+        if iter.peek().is_none() {
+            continue;
+        }
+
+        for (stack_idx, ch_idx) in (1..line.len()).step_by(4).enumerate() {
+            let ch = line.chars().nth(ch_idx).unwrap();
+            if ch == ' ' {
+                continue;
+            }
+
+            if stack_idx >= stacks.len() {
+                init_until(stack_idx, &mut stacks);
+            }
+            stacks[stack_idx].push(ch);
+        }
+    }
+
+    for stack in stacks.iter_mut() {
+        stack.reverse();
+    }
+
+    stacks
+}
+
+/// `None` if the move takes more crates than the stack holds.
+fn apply_move(stacks: &mut [Vec<char>], mv: &Move) -> Option<()> {
+    for _ in 0..mv.amount {
+        let val = stacks[mv.from].pop()?;
+        stacks[mv.to].push(val);
+    }
+
+    Some(())
+}
+
+fn apply_move_v2(stacks: &mut [Vec<char>], mv: &Move) -> Option<()> {
+    let len = stacks[mv.from].len();
+    let slice = stacks[mv.from].split_off(len.checked_sub(mv.amount)?);
+    stacks[mv.to].extend(slice);
+
+    Some(())
+}
+
+// an empty stack has no crate on top to read
+fn get_tops(stacks: &[Vec<char>]) -> String {
+    stacks.iter().filter_map(|stack| stack.last()).collect()
+}
+
+fn process_moves<F>(stacks: &mut [Vec<char>], moves: &[Move], f: F) -> Option<String>
+where
+    F: Fn(&mut [Vec<char>], &Move) -> Option<()>,
+{
+    for mv in moves {
+        f(stacks, mv)?;
+    }
+
+    Some(get_tops(stacks))
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Procedure;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_file(input)
+    }
+
+    fn part1(procedure: &Self::Input) -> Answer {
+        let mut stacks = procedure.stacks.clone();
+        process_moves(&mut stacks, &procedure.moves, apply_move)
+            .map_or(Answer::NoSolution, Answer::from)
+    }
+
+    fn part2(procedure: &Self::Input) -> Answer {
+        let mut stacks = procedure.stacks.clone();
+        process_moves(&mut stacks, &procedure.moves, apply_move_v2)
+            .map_or(Answer::NoSolution, Answer::from)
+    }
+}
@@ -0,0 +1,62 @@
+use aoc_common::{Answer, ParseError, Result, Solution};
+
+fn has_duplicates(slice: &[char]) -> bool {
+    let mut v = Vec::from(slice);
+    let len = v.len();
+
+    v.sort();
+    v.dedup();
+
+    v.len() < len
+}
+
+fn get_first_unique_window(n: usize, chars: &[char]) -> Option<usize> {
+    for (i, window) in chars.windows(n).enumerate() {
+        if !has_duplicates(window) {
+            return Some(i + n);
+        }
+    }
+
+    None
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<char>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let signal = input.trim_end();
+
+        match signal
+            .char_indices()
+            .find(|(_, ch)| !ch.is_ascii_lowercase())
+        {
+            Some((i, ch)) => Err(ParseError::new("invalid character in datastream")
+                .locate(signal, &signal[i..i + ch.len_utf8()])
+                .on_line(1)),
+            None => Ok(signal.chars().collect()),
+        }
+    }
+
+    fn part1(chars: &Self::Input) -> Answer {
+        get_first_unique_window(4, chars).map_or(Answer::NoSolution, Answer::from)
+    }
+
+    fn part2(chars: &Self::Input) -> Answer {
+        get_first_unique_window(14, chars).map_or(Answer::NoSolution, Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_works() {
+        assert_eq!(Day6::parse("abc\n").unwrap(), ['a', 'b', 'c']);
+
+        let err = Day6::parse("abé").unwrap_err();
+        assert_eq!((err.line(), err.column()), (Some(1), Some(3)));
+    }
+}
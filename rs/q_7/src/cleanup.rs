@@ -0,0 +1,359 @@
+use crate::fs::{FileSystem, NodeId};
+
+/// The disk the file system lives on, and how small a directory has to be to count for part 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiskConfig {
+    pub capacity: usize,
+    /// The free space the update needs
+    pub required_free: usize,
+    pub small_dir_max: usize,
+}
+
+impl DiskConfig {
+    /// The values given by the puzzle.
+    pub const PUZZLE: DiskConfig = DiskConfig {
+        capacity: 70000000,
+        required_free: 30000000,
+        small_dir_max: 100000,
+    };
+
+    /// How much has to be deleted from `fs` to get enough free space.
+    pub fn to_free(&self, fs: &FileSystem) -> usize {
+        (fs.size(fs.root()) + self.required_free).saturating_sub(self.capacity)
+    }
+}
+
+/// Directories to delete, none of them inside another.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CleanupPlan {
+    pub dirs: Vec<NodeId>,
+    /// The total size of the deleted directories
+    pub freed: usize,
+}
+
+impl CleanupPlan {
+    fn new(fs: &FileSystem, dirs: Vec<NodeId>) -> Self {
+        Self {
+            freed: dirs.iter().map(|dir| fs.size(*dir)).sum(),
+            dirs,
+        }
+    }
+}
+
+/// The smallest single directory that frees enough space, or `None` if even the root is too small.
+pub fn plan_single(fs: &FileSystem, config: &DiskConfig) -> Option<CleanupPlan> {
+    let to_free = config.to_free(fs);
+    let dir = fs
+        .dirs()
+        .filter(|dir| fs.size(*dir) >= to_free)
+        .min_by_key(|dir| fs.size(*dir))?;
+
+    Some(CleanupPlan::new(fs, vec![dir]))
+}
+
+/// The directories freeing enough space with the smallest total size, or `None` if even the root
+/// is too small.
+///
+/// Deleting a directory deletes everything inside it, so no chosen directory is inside another.
+/// This is a knapsack over the sizes up to the best single directory, so it takes time
+/// proportional to the number of directories times that size, and memory to the depth of the
+/// tree times that size.
+pub fn plan_optimal(fs: &FileSystem, config: &DiskConfig) -> Option<CleanupPlan> {
+    let to_free = config.to_free(fs);
+    // deleting more than the best single directory is never worth it
+    let limit = plan_single(fs, config)?.freed;
+    let knapsack = Knapsack::new(fs, limit);
+
+    let (reachable, _) = knapsack.run(knapsack.dirs.len(), None);
+    let best = (to_free..=limit).find(|sum| reachable.get(*sum))?;
+
+    // walk back through the directory that first reached each sum, which comes before the
+    // directories reaching the rest of it
+    let mut chosen = vec![];
+    let (mut sum, mut position) = (best, knapsack.dirs.len());
+    while sum > 0 {
+        let (_, taken) = knapsack.run(position, Some(sum));
+        let taken = taken.unwrap();
+        chosen.push(knapsack.dirs[taken]);
+        sum -= fs.size(knapsack.dirs[taken]);
+        position = taken;
+    }
+    chosen.reverse();
+
+    Some(CleanupPlan::new(fs, chosen))
+}
+
+/// The sums of directory sizes that can be deleted together, going through the directories in
+/// pre-order where taking one skips its subtree.
+struct Knapsack<'a> {
+    fs: &'a FileSystem,
+    dirs: Vec<NodeId>,
+    /// The position right after the subtree of every directory
+    ends: Vec<usize>,
+    limit: usize,
+}
+
+impl<'a> Knapsack<'a> {
+    fn new(fs: &'a FileSystem, limit: usize) -> Self {
+        let dirs: Vec<_> = fs.dirs().collect();
+        let ends = subtree_ends(fs, &dirs);
+
+        Self {
+            fs,
+            dirs,
+            ends,
+            limit,
+        }
+    }
+
+    /// The sums reachable using the directories before `last`, along with the directory that
+    /// first made `watch` reachable.
+    ///
+    /// The sums reachable before a position only grow from one position to the next, so only the
+    /// sums reachable before every open directory have to be kept.
+    fn run(&self, last: usize, watch: Option<usize>) -> (Bits, Option<usize>) {
+        let mut reachable = Bits::new(self.limit + 1);
+        reachable.set(0);
+        let mut before: Vec<Option<Bits>> = vec![None; self.dirs.len()];
+        let mut arrivals: Vec<Vec<usize>> = vec![vec![]; self.dirs.len() + 1];
+        let mut reached_by = None;
+
+        for position in 0..=last {
+            for taken in std::mem::take(&mut arrivals[position]) {
+                let bits = before[taken].take().unwrap();
+                let new = bits
+                    .shifted(self.fs.size(self.dirs[taken]))
+                    .without(&reachable);
+                if watch.is_some_and(|sum| new.get(sum)) {
+                    reached_by = Some(taken);
+                }
+                reachable.union_with(&new);
+            }
+
+            if position < last {
+                before[position] = Some(reachable.clone());
+                arrivals[self.ends[position]].push(position);
+            }
+        }
+
+        (reachable, reached_by)
+    }
+}
+
+/// For every directory in `dirs`, given in pre-order, the position right after its subtree.
+fn subtree_ends(fs: &FileSystem, dirs: &[NodeId]) -> Vec<usize> {
+    // how many directories every subtree holds, itself included
+    let mut counts = vec![1; fs.len()];
+    for id in fs.post_order(fs.root()) {
+        if let (Some(parent), true) = (fs.parent(id), fs.is_dir(id)) {
+            counts[parent.index()] += counts[id.index()];
+        }
+    }
+
+    dirs.iter()
+        .enumerate()
+        .map(|(position, dir)| position + counts[dir.index()])
+        .collect()
+}
+
+/// A fixed-size set of small numbers.
+#[derive(Debug, Clone)]
+struct Bits {
+    len: usize,
+    words: Vec<u64>,
+}
+
+impl Bits {
+    fn new(len: usize) -> Self {
+        Self {
+            len,
+            words: vec![0; len.div_ceil(64)],
+        }
+    }
+
+    fn get(&self, i: usize) -> bool {
+        i < self.len && self.words[i / 64] & (1 << (i % 64)) != 0
+    }
+
+    fn set(&mut self, i: usize) {
+        self.words[i / 64] |= 1 << (i % 64);
+    }
+
+    /// Every number plus `by`, dropping the ones that don't fit anymore.
+    fn shifted(&self, by: usize) -> Self {
+        let mut res = Self::new(self.len);
+        let (words, bits) = (by / 64, by % 64);
+
+        for i in words..res.words.len() {
+            let low = self.words[i - words];
+            res.words[i] = match (bits, i > words) {
+                (0, _) => low,
+                (_, true) => (low << bits) | (self.words[i - words - 1] >> (64 - bits)),
+                (_, false) => low << bits,
+            };
+        }
+        if let Some(last) = res.words.last_mut() {
+            let used = res.len % 64;
+            if used != 0 {
+                *last &= (1 << used) - 1;
+            }
+        }
+
+        res
+    }
+
+    fn without(mut self, other: &Self) -> Self {
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word &= !other;
+        }
+        self
+    }
+
+    fn union_with(&mut self, other: &Self) {
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word |= other;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, random_fs, SyntheticTree};
+
+    const DEMO: &str = include_str!("../data/demo.txt");
+
+    fn paths(fs: &FileSystem, plan: &CleanupPlan) -> Vec<String> {
+        plan.dirs.iter().map(|dir| fs.path(*dir)).collect()
+    }
+
+    #[test]
+    fn plan_single_works() {
+        let fs = parse(DEMO).unwrap();
+
+        let plan = plan_single(&fs, &DiskConfig::PUZZLE).unwrap();
+        assert_eq!(paths(&fs, &plan), ["/d"]);
+        assert_eq!(plan.freed, 24933642);
+
+        let small_disk = DiskConfig {
+            capacity: 1000,
+            ..DiskConfig::PUZZLE
+        };
+        assert_eq!(plan_single(&fs, &small_disk), None);
+    }
+
+    #[test]
+    fn plan_optimal_works() {
+        let fs = parse(DEMO).unwrap();
+
+        // /d alone beats /a and /d together
+        let plan = plan_optimal(&fs, &DiskConfig::PUZZLE).unwrap();
+        assert_eq!(paths(&fs, &plan), ["/d"]);
+
+        // nothing short of / frees enough
+        let config = DiskConfig {
+            required_free: 51_000_000,
+            ..DiskConfig::PUZZLE
+        };
+        assert_eq!(config.to_free(&fs), 29381165);
+        assert_eq!(paths(&fs, &plan_single(&fs, &config).unwrap()), ["/"]);
+        let plan = plan_optimal(&fs, &config).unwrap();
+        assert_eq!(paths(&fs, &plan), ["/"]);
+
+        // with /a/e and /d, just enough
+        let config = DiskConfig {
+            capacity: 48381165 + 30000000 - 24934226,
+            ..DiskConfig::PUZZLE
+        };
+        let plan = plan_optimal(&fs, &config).unwrap();
+        assert_eq!(paths(&fs, &plan), ["/a/e", "/d"]);
+        assert_eq!(plan.freed, 24934226);
+        assert_eq!(paths(&fs, &plan_single(&fs, &config).unwrap()), ["/"]);
+
+        let roomy = DiskConfig {
+            capacity: 100_000_000,
+            ..DiskConfig::PUZZLE
+        };
+        assert_eq!(plan_optimal(&fs, &roomy).unwrap().freed, 0);
+    }
+
+    /// Every set of directories that aren't inside one another, by brute force.
+    fn best_by_brute_force(fs: &FileSystem, to_free: usize) -> Option<usize> {
+        let dirs: Vec<_> = fs.dirs().collect();
+        let inside = |inner: NodeId, outer: NodeId| {
+            let mut current = Some(inner);
+            while let Some(dir) = current {
+                if dir == outer {
+                    return true;
+                }
+                current = fs.parent(dir);
+            }
+            false
+        };
+
+        (0..1u32 << dirs.len())
+            .map(|mask| {
+                (0..dirs.len())
+                    .filter(|i| mask & (1 << i) != 0)
+                    .map(|i| dirs[i])
+                    .collect::<Vec<_>>()
+            })
+            .filter(|chosen| {
+                chosen
+                    .iter()
+                    .all(|a| chosen.iter().all(|b| a == b || !inside(*a, *b)))
+            })
+            .map(|chosen| chosen.iter().map(|dir| fs.size(*dir)).sum::<usize>())
+            .filter(|sum| *sum >= to_free)
+            .min()
+    }
+
+    #[test]
+    fn plan_optimal_matches_brute_force() {
+        for seed in 0..30 {
+            let fs = random_fs(&SyntheticTree {
+                seed,
+                max_depth: 3,
+                max_entries: 10,
+                max_file_size: 1000,
+            });
+            if fs.dirs().count() > 12 {
+                continue;
+            }
+
+            let config = DiskConfig {
+                capacity: fs.size(fs.root()),
+                required_free: fs.size(fs.root()) / 3 + 1,
+                small_dir_max: 0,
+            };
+            let plan = plan_optimal(&fs, &config);
+            assert_eq!(
+                plan.as_ref().map(|plan| plan.freed),
+                best_by_brute_force(&fs, config.to_free(&fs)),
+                "seed {}",
+                seed
+            );
+            if let Some(plan) = plan {
+                assert_eq!(
+                    plan.dirs.iter().map(|dir| fs.size(*dir)).sum::<usize>(),
+                    plan.freed
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn bits_work() {
+        let mut bits = Bits::new(130);
+        bits.set(0);
+        bits.set(3);
+        bits.set(100);
+
+        let ones = |bits: &Bits| (0..130).filter(|i| bits.get(*i)).collect::<Vec<_>>();
+        assert_eq!(ones(&bits.shifted(64)), [64, 67]);
+        assert_eq!(ones(&bits.shifted(29)), [29, 32, 129]);
+        assert_eq!(ones(&bits.shifted(29).without(&bits)), [29, 32, 129]);
+        assert_eq!(ones(&bits.shifted(97).without(&bits)), [97]);
+        assert!(bits.get(100) && !bits.get(101) && !bits.get(500));
+    }
+}
@@ -1,168 +1,223 @@
-use std::fmt::Write;
-use std::{cell::RefCell, rc::Rc};
-
-pub type RcFile = Rc<RefCell<File>>;
-
-#[derive(Debug)]
-pub struct File {
-    pub name: String,
-    pub is_dir: bool,
-    pub size: Option<usize>,
-    pub parent: Option<RcFile>,
-    pub children: Vec<RcFile>,
-}
+use aoc_common::{parse_number, ParseError, Result};
 
-impl File {
-    pub fn new_rc(file: File) -> RcFile {
-        Rc::new(RefCell::new(file))
-    }
+use crate::fs::{FileSystem, NodeId};
 
-    pub fn new(name: &str, is_dir: bool) -> Self {
-        Self {
-            name: name.to_owned(),
-            is_dir,
-            size: None,
-            parent: None,
-            children: vec![],
-        }
-    }
+/// A command typed at the `$` prompt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command<'a> {
+    Cd(&'a str),
+    Ls,
+}
 
-    pub fn print(&self, sep: &str) -> String {
-        let mut res = String::new();
-        writeln!(
-            res,
-            "{}{} {} {}",
-            sep,
-            self.name,
-            if self.is_dir { "dir" } else { "file" },
-            match self.size {
-                Some(size) => size.to_string(),
-                None => "Unknown".to_owned(),
-            }
-        );
+/// A line printed by `ls`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Entry<'a> {
+    Dir(&'a str),
+    File(&'a str, usize),
+}
 
-        for child in self.children.iter() {
-            let mut new_sep = String::from(sep);
-            new_sep.push_str("-");
-            res.push_str(&child.borrow().print(&new_sep));
+impl Entry<'_> {
+    pub fn name(&self) -> &str {
+        match self {
+            Entry::Dir(name) | Entry::File(name, _) => name,
         }
-
-        res
     }
+}
 
-    pub fn get_parent(&self) -> Option<RcFile> {
-        self.parent.clone()
+pub fn parse_command(line: &str) -> Result<Command<'_>> {
+    let command = line
+        .strip_prefix("$ ")
+        .ok_or_else(|| ParseError::new("expected a command").with_text(line))?;
+    // directory names can have spaces, so `cd` takes the rest of the line
+    if let Some(target) = command
+        .strip_prefix("cd ")
+        .filter(|target| !target.is_empty())
+    {
+        return Ok(Command::Cd(target));
     }
+    let mut words = command.split_whitespace();
 
-    pub fn set_parent(&mut self, parent: RcFile) {
-        self.parent = Some(parent);
+    match (words.next(), words.next(), words.next()) {
+        (Some("cd"), _, _) => Err(ParseError::new("expected `$ cd <dir>`").with_text(line)),
+        (Some("ls"), None, _) => Ok(Command::Ls),
+        (Some("ls"), Some(arg), _) => {
+            Err(ParseError::new("`ls` doesn't take arguments").locate(line, arg))
+        }
+        (Some(name), _, _) => Err(ParseError::new("unknown command").locate(line, name)),
+        (None, _, _) => Err(ParseError::new("expected a command").with_text(line)),
     }
+}
 
-    pub fn add_child(&mut self, child: RcFile) {
-        self.children.push(child);
+/// Check that `name`, taken from `line`, can be part of a path.
+fn check_name<'a>(line: &str, name: &'a str) -> Result<&'a str> {
+    if name.is_empty() {
+        return Err(ParseError::new("expected a name").with_text(line));
+    }
+    match name.find('/') {
+        Some(i) => Err(ParseError::new("names can't contain `/`").locate(line, &name[i..i + 1])),
+        None => Ok(name),
     }
 }
 
-pub fn parse_ls(dir: RcFile, start_idx: usize, lines: &[&str]) -> usize {
-    let mut i = start_idx + 1;
-    let mut iter = lines.iter().skip(i);
-    while let Some(line) = iter.next() {
-        if line.starts_with('$') {
-            return i;
-        }
-
-        i += 1;
-
-        let parts: Vec<&str> = line.split(' ').collect();
-        let name = parts[1];
-        let is_dir = parts[0] == "dir";
-        let file = File::new_rc(File::new(name, is_dir));
-        dir.borrow_mut().add_child(file.clone());
-        file.borrow_mut().set_parent(dir.clone());
-        if !is_dir {
-            file.borrow_mut().size = parts[0].parse::<usize>().ok();
+pub fn parse_entry(line: &str) -> Result<Entry<'_>> {
+    let (kind, name) = line.split_once(' ').ok_or_else(|| {
+        ParseError::new("expected `dir <name>` or `<size> <name>`").with_text(line)
+    })?;
+    let name = check_name(line, name)?;
+
+    match kind {
+        "dir" => Ok(Entry::Dir(name)),
+        _ => {
+            let size = parse_number(kind).map_err(|err| err.locate(line, kind))?;
+            Ok(Entry::File(name, size))
         }
     }
-
-    i
 }
 
-pub fn parse_cd(cwd: RcFile, line: &str) -> RcFile {
-    let mut parts = line.split(' ');
-    let name = parts.nth(2).unwrap();
-    if name == ".." {
-        return cwd.borrow().get_parent().unwrap();
+/// Move from `cwd` to `target`, creating the directory if no `ls` listed it yet.
+pub fn parse_cd(fs: &mut FileSystem, cwd: NodeId, line: &str, target: &str) -> Result<NodeId> {
+    match target {
+        "/" => Ok(fs.root()),
+        ".." => fs.parent(cwd).ok_or_else(|| {
+            ParseError::new("the root directory has no parent").locate(line, target)
+        }),
+        _ => match fs.child(cwd, check_name(line, target)?) {
+            Some(child) if fs.is_dir(child) => Ok(child),
+            Some(_) => Err(ParseError::new("can't cd into a file").locate(line, target)),
+            None => Ok(fs.add_dir(cwd, target)),
+        },
     }
+}
 
-    for child in cwd.borrow().children.iter() {
-        if child.borrow().name == name {
-            return child.clone();
+/// Add an entry listed by `ls` to `dir`.
+///
+/// Listing a directory again only adds what's new, an entry that doesn't match what was listed
+/// before under the same name is an error.
+pub fn add_entry(fs: &mut FileSystem, dir: NodeId, line: &str, entry: Entry) -> Result<()> {
+    let existing = match fs.child(dir, entry.name()) {
+        Some(existing) => existing,
+        None => {
+            match entry {
+                Entry::Dir(name) => fs.add_dir(dir, name),
+                Entry::File(name, size) => fs.add_file(dir, name, size),
+            };
+            return Ok(());
+        }
+    };
+
+    let matches = match entry {
+        Entry::Dir(_) => fs.is_dir(existing),
+        Entry::File(_, size) => !fs.is_dir(existing) && fs.size(existing) == size,
+    };
+    match matches {
+        true => Ok(()),
+        false => {
+            Err(ParseError::new("doesn't match an earlier listing").locate(line, entry.name()))
         }
     }
-
-    unreachable!("Couldn't find dir to cd into.");
 }
 
-pub fn parse(cwd: RcFile, idx: usize, lines: &[&str]) {
-    if idx >= lines.len() {
-        return;
-    }
-
-    let line = lines[idx];
-    if line.starts_with("$ cd") {
-        let next = parse_cd(cwd, line);
-        return parse(next, idx + 1, lines);
+/// Rebuild the file system explored by a shell transcript.
+///
+/// The transcript starts in the root directory, and directories can be entered before they're
+/// listed.
+pub fn parse(input: &str) -> Result<FileSystem> {
+    let mut fs = FileSystem::new();
+    let mut cwd = fs.root();
+    let mut listing = false;
+
+    for (i, line) in input.lines().enumerate() {
+        let res = if line.starts_with('$') {
+            parse_command(line).and_then(|command| {
+                listing = command == Command::Ls;
+                if let Command::Cd(target) = command {
+                    cwd = parse_cd(&mut fs, cwd, line, target)?;
+                }
+                Ok(())
+            })
+        } else if line.is_empty() {
+            Ok(())
+        } else if listing {
+            parse_entry(line).and_then(|entry| add_entry(&mut fs, cwd, line, entry))
+        } else {
+            Err(ParseError::new("expected a command").with_text(line))
+        };
+
+        res.map_err(|err| err.on_line(i + 1))?;
     }
 
-    if (line.starts_with("$ ls")) {
-        let end_idx = parse_ls(cwd.clone(), idx, lines);
-        return parse(cwd, end_idx, lines);
-    }
-
-    unreachable!("Trying to parse wrong line.");
+    Ok(fs)
 }
 
-pub fn update_dir_sizes(cwd: RcFile) {
-    let mut size = 0;
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    for child in cwd.borrow().children.iter() {
-        if child.borrow().is_dir {
-            update_dir_sizes(child.clone());
-        }
+    const DEMO: &str = include_str!("../data/demo.txt");
 
-        size += child.borrow().size.unwrap();
+    #[test]
+    fn parse_command_works() {
+        assert_eq!(parse_command("$ cd a"), Ok(Command::Cd("a")));
+        assert_eq!(parse_command("$ cd a b"), Ok(Command::Cd("a b")));
+        assert_eq!(parse_command("$ ls"), Ok(Command::Ls));
+        assert_eq!(parse_entry("dir a"), Ok(Entry::Dir("a")));
+        assert_eq!(parse_entry("584 i"), Ok(Entry::File("i", 584)));
+
+        let err = parse_command("$ rm -rf a").unwrap_err();
+        assert_eq!(err.to_string(), "column 3: unknown command: \"rm\"");
+        assert!(parse_command("$ cd").is_err());
+        assert!(parse_command("$ ls a").is_err());
+        assert!(parse_entry("x").is_err());
+
+        let err = parse_entry("dir ").unwrap_err();
+        assert_eq!(err.message(), "expected a name");
+        let err = parse_entry("10 a/b").unwrap_err();
+        assert_eq!(err.to_string(), "column 5: names can't contain `/`: \"/\"");
     }
 
-    cwd.borrow_mut().size = Some(size);
-}
+    #[test]
+    fn parse_works() {
+        let fs = parse(DEMO).unwrap();
 
-pub fn walk<F>(cwd: RcFile, callback: &mut F)
-where
-    F: FnMut(RcFile) -> (),
-{
-    for child in cwd.borrow().children.iter() {
-        if child.borrow().is_dir {
-            walk(child.clone(), callback);
-        }
+        assert_eq!(fs.size(fs.root()), 48381165);
+        assert_eq!(fs.size(fs.lookup("/a").unwrap()), 94853);
+        assert_eq!(fs.size(fs.lookup("/d").unwrap()), 24933642);
+        assert_eq!(fs.children(fs.root()).len(), 4);
+    }
 
-        callback(child.clone());
+    #[test]
+    fn parse_handles_any_transcript() {
+        // no leading `cd /`, entering unlisted directories, going back to the root and listing
+        // the same directory twice
+        let transcript = "$ cd a\n$ cd b\n$ ls\n10 x\n$ cd /\n$ ls\ndir a\n5 y\n$ ls\n5 y\n";
+        let fs = parse(transcript).unwrap();
+
+        assert_eq!(fs.size(fs.root()), 15);
+        assert_eq!(fs.size(fs.lookup("/a/b").unwrap()), 10);
+        assert_eq!(fs.children(fs.root()).len(), 2);
+        assert_eq!(fs.len(), 5);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use std::{fs::read_to_string, path::Path};
+    #[test]
+    fn parse_errors_work() {
+        let err = parse("$ cd /\n$ ls\n10 x\n$ pwd").unwrap_err();
+        assert_eq!(err.line(), Some(4));
+        assert_eq!(err.message(), "unknown command");
+
+        let err = parse("$ ls\n10 x\n$ ls\n20 x").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 4: doesn't match an earlier listing: \"x\""
+        );
 
-    use super::*;
+        assert_eq!(parse("$ cd ..").unwrap_err().line(), Some(1));
+        assert_eq!(parse("$ ls\n10 x\n$ cd x").unwrap_err().line(), Some(3));
+        assert_eq!(parse("10 x").unwrap_err().line(), Some(1));
 
-    #[test]
-    fn parse_ls_works() {
-        let contents = read_to_string(&Path::new("./data/demo.txt")).unwrap();
-        let lines = contents.lines().collect::<Vec<&str>>();
-
-        let mut root = File::new_rc(File::new("/", true));
-        let next_idx = parse_ls(root.clone(), 1, &lines);
-        assert_eq!(6, next_idx);
-        assert_eq!(4, root.borrow().children.len());
+        let err = parse("$ cd /\n$ cd a/b").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 7: names can't contain `/`: \"/\""
+        );
     }
 }
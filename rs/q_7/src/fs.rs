@@ -0,0 +1,299 @@
+/// A node of a [`FileSystem`], only meaningful for the file system that handed it out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(usize);
+
+impl NodeId {
+    /// Where the node sits in the arena, below [`FileSystem::len`], to index side tables with.
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    name: String,
+    is_dir: bool,
+    /// The size of a file, or the total size of everything below a directory
+    size: usize,
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+}
+
+impl Node {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn is_dir(&self) -> bool {
+        self.is_dir
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn parent(&self) -> Option<NodeId> {
+        self.parent
+    }
+
+    pub fn children(&self) -> &[NodeId] {
+        &self.children
+    }
+}
+
+/// A directory tree with every node stored in a single `Vec`, the root `/` being the first one.
+///
+/// Directory sizes are kept up to date as files are added, so they never have to be recomputed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileSystem {
+    nodes: Vec<Node>,
+}
+
+impl Default for FileSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FileSystem {
+    /// A file system holding nothing but an empty root directory.
+    pub fn new() -> Self {
+        Self {
+            nodes: vec![Node {
+                name: "/".to_owned(),
+                is_dir: true,
+                size: 0,
+                parent: None,
+                children: vec![],
+            }],
+        }
+    }
+
+    pub fn root(&self) -> NodeId {
+        NodeId(0)
+    }
+
+    pub fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id.0]
+    }
+
+    pub fn name(&self, id: NodeId) -> &str {
+        self.node(id).name()
+    }
+
+    pub fn is_dir(&self, id: NodeId) -> bool {
+        self.node(id).is_dir()
+    }
+
+    pub fn size(&self, id: NodeId) -> usize {
+        self.node(id).size()
+    }
+
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.node(id).parent()
+    }
+
+    pub fn children(&self, id: NodeId) -> &[NodeId] {
+        self.node(id).children()
+    }
+
+    /// The child of `dir` called `name`, if there is one.
+    pub fn child(&self, dir: NodeId, name: &str) -> Option<NodeId> {
+        self.children(dir)
+            .iter()
+            .copied()
+            .find(|child| self.name(*child) == name)
+    }
+
+    /// The number of nodes, the root included.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        // there's always a root
+        false
+    }
+
+    /// Add an empty directory called `name` to `dir`.
+    pub fn add_dir(&mut self, dir: NodeId, name: &str) -> NodeId {
+        self.add_node(dir, name, true)
+    }
+
+    /// Add a file of `size` called `name` to `dir`, growing the size of every directory above it.
+    pub fn add_file(&mut self, dir: NodeId, name: &str, size: usize) -> NodeId {
+        let id = self.add_node(dir, name, false);
+
+        let mut current = Some(id);
+        while let Some(node) = current {
+            self.nodes[node.0].size += size;
+            current = self.parent(node);
+        }
+
+        id
+    }
+
+    fn add_node(&mut self, dir: NodeId, name: &str, is_dir: bool) -> NodeId {
+        assert!(self.is_dir(dir), "{} isn't a directory", self.path(dir));
+
+        let id = NodeId(self.nodes.len());
+        self.nodes.push(Node {
+            name: name.to_owned(),
+            is_dir,
+            size: 0,
+            parent: Some(dir),
+            children: vec![],
+        });
+        self.nodes[dir.0].children.push(id);
+
+        id
+    }
+
+    /// The absolute path of `id`, like `/a/e`.
+    pub fn path(&self, id: NodeId) -> String {
+        let mut names = vec![];
+        let mut current = id;
+        while let Some(parent) = self.parent(current) {
+            names.push(self.name(current));
+            current = parent;
+        }
+        names.reverse();
+
+        format!("/{}", names.join("/"))
+    }
+
+    /// The node at `path`, which is always resolved from the root.
+    pub fn lookup(&self, path: &str) -> Option<NodeId> {
+        path.split('/')
+            .filter(|name| !name.is_empty())
+            .try_fold(self.root(), |dir, name| self.child(dir, name))
+    }
+
+    /// `id` and everything below it, every directory before its children.
+    pub fn pre_order(&self, id: NodeId) -> PreOrder<'_> {
+        PreOrder {
+            fs: self,
+            stack: vec![id],
+        }
+    }
+
+    /// `id` and everything below it, every directory after its children.
+    pub fn post_order(&self, id: NodeId) -> PostOrder<'_> {
+        PostOrder {
+            fs: self,
+            stack: vec![(id, false)],
+        }
+    }
+
+    /// Every directory, the root included, in pre-order.
+    pub fn dirs(&self) -> impl Iterator<Item = NodeId> + '_ {
+        self.pre_order(self.root()).filter(|id| self.is_dir(*id))
+    }
+}
+
+pub struct PreOrder<'a> {
+    fs: &'a FileSystem,
+    stack: Vec<NodeId>,
+}
+
+impl Iterator for PreOrder<'_> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let id = self.stack.pop()?;
+        self.stack
+            .extend(self.fs.children(id).iter().rev().copied());
+
+        Some(id)
+    }
+}
+
+pub struct PostOrder<'a> {
+    fs: &'a FileSystem,
+    /// Nodes along with whether their children were already pushed
+    stack: Vec<(NodeId, bool)>,
+}
+
+impl Iterator for PostOrder<'_> {
+    type Item = NodeId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (id, expanded) = self.stack.pop()?;
+            if expanded || self.fs.children(id).is_empty() {
+                return Some(id);
+            }
+
+            self.stack.push((id, true));
+            self.stack.extend(
+                self.fs
+                    .children(id)
+                    .iter()
+                    .rev()
+                    .map(|child| (*child, false)),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // /a/e/i, /a/f and /b
+    fn small_fs() -> FileSystem {
+        let mut fs = FileSystem::new();
+        let a = fs.add_dir(fs.root(), "a");
+        let e = fs.add_dir(a, "e");
+        fs.add_file(e, "i", 584);
+        fs.add_file(a, "f", 29116);
+        fs.add_file(fs.root(), "b", 100);
+        fs
+    }
+
+    fn names(fs: &FileSystem, ids: impl Iterator<Item = NodeId>) -> Vec<&str> {
+        ids.map(|id| fs.name(id)).collect()
+    }
+
+    #[test]
+    fn sizes_work() {
+        let fs = small_fs();
+
+        assert_eq!(fs.size(fs.root()), 29800);
+        assert_eq!(fs.size(fs.lookup("/a").unwrap()), 29700);
+        assert_eq!(fs.size(fs.lookup("/a/e").unwrap()), 584);
+        assert_eq!(fs.size(fs.lookup("/b").unwrap()), 100);
+    }
+
+    #[test]
+    fn navigation_works() {
+        let fs = small_fs();
+        let e = fs.lookup("/a/e").unwrap();
+
+        assert_eq!(fs.path(e), "/a/e");
+        assert_eq!(fs.path(fs.root()), "/");
+        assert_eq!(fs.lookup("/"), Some(fs.root()));
+        assert_eq!(fs.lookup("a/e/"), Some(e));
+        assert_eq!(fs.lookup("/a/x"), None);
+        assert_eq!(fs.parent(e), fs.lookup("/a"));
+        assert_eq!(fs.parent(fs.root()), None);
+        assert_eq!(fs.child(e, "i"), fs.lookup("/a/e/i"));
+        assert_eq!(fs.len(), 6);
+    }
+
+    #[test]
+    fn iterators_work() {
+        let fs = small_fs();
+
+        assert_eq!(
+            names(&fs, fs.pre_order(fs.root())),
+            ["/", "a", "e", "i", "f", "b"]
+        );
+        assert_eq!(
+            names(&fs, fs.post_order(fs.root())),
+            ["i", "e", "f", "a", "b", "/"]
+        );
+        assert_eq!(names(&fs, fs.dirs()), ["/", "a", "e"]);
+        assert_eq!(names(&fs, fs.post_order(fs.lookup("/b").unwrap())), ["b"]);
+    }
+}
@@ -0,0 +1,38 @@
+mod cleanup;
+mod common;
+mod fs;
+mod part1;
+mod part2;
+mod report;
+mod transcript;
+
+use aoc_common::{Answer, Result, Solution};
+
+pub use cleanup::{plan_optimal, plan_single, CleanupPlan, DiskConfig};
+pub use common::*;
+pub use fs::{FileSystem, Node, NodeId, PostOrder, PreOrder};
+pub use part1::part1;
+pub use part2::part2;
+pub use report::{du, human_size, largest_dirs, to_json, tree, JsonKind, JsonNode};
+pub use transcript::{from_dir, random_fs, transcript, Order, SyntheticTree};
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = FileSystem;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(fs: &Self::Input) -> Answer {
+        part1(fs, &DiskConfig::PUZZLE).into()
+    }
+
+    fn part2(fs: &Self::Input) -> Answer {
+        match part2(fs, &DiskConfig::PUZZLE) {
+            Some(size) => size.into(),
+            None => Answer::NoSolution,
+        }
+    }
+}
@@ -1,26 +1,197 @@
-#![allow(unused)]
+use std::{env, path::Path, process};
 
-mod common;
-mod part1;
-mod part2;
+use aoc_common::{InputSource, Solution};
+use q_7::{
+    du, from_dir, human_size, largest_dirs, part1, part2, plan_optimal, plan_single, random_fs,
+    to_json, transcript, tree, Day7, DiskConfig, FileSystem, Order, SyntheticTree,
+};
 
-use std::{rc::Rc, cell::RefCell, fs::read_to_string, path::Path};
+/// What to print instead of the answers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Report {
+    Tree,
+    Du,
+    Top(usize),
+    Json,
+    Transcript(Order),
+    /// The directories to delete, the smallest single one or the best set of them
+    Plan {
+        optimal: bool,
+    },
+}
+
+/// Where the file system comes from when it isn't parsed from a transcript.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Tree {
+    Dir(String),
+    Random(u64),
+}
+
+#[derive(Debug)]
+struct Options {
+    report: Option<Report>,
+    tree: Option<Tree>,
+    disk: DiskConfig,
+}
+
+fn parse_value<T>(arg: &str, value: Option<String>) -> Result<T, String>
+where
+    T: std::str::FromStr,
+{
+    let value = value.ok_or_else(|| format!("Missing value for {}", arg))?;
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value for {}: {}", arg, value))
+}
+
+fn parse_order(value: &str) -> Result<Order, String> {
+    match value.split_once(':') {
+        None if value == "depth" => Ok(Order::DepthFirst),
+        None if value == "breadth" => Ok(Order::BreadthFirst),
+        None if value == "random" => Ok(Order::Random(0)),
+        Some(("random", seed)) => seed
+            .parse()
+            .map(Order::Random)
+            .map_err(|_| format!("Invalid seed: {}", seed)),
+        _ => Err(format!(
+            "Expected depth, breadth or random[:<seed>], got {}",
+            value
+        )),
+    }
+}
 
-use common::*;
-use part1::part1;
+fn parse_options(args: impl IntoIterator<Item = String>) -> Result<(Options, Vec<String>), String> {
+    let mut options = Options {
+        report: None,
+        tree: None,
+        disk: DiskConfig::PUZZLE,
+    };
+    let mut rest = vec![];
+    let mut args = args.into_iter();
 
-use crate::part2::part2;
+    while let Some(arg) = args.next() {
+        let report = match arg.as_str() {
+            "--tree" => Report::Tree,
+            "--du" => Report::Du,
+            "--json" => Report::Json,
+            "--top" => Report::Top(parse_value(&arg, args.next())?),
+            "--transcript" => {
+                let order = args.next().ok_or("Missing value for --transcript")?;
+                Report::Transcript(parse_order(&order)?)
+            }
+            "--plan" => match args.next().as_deref() {
+                Some("single") => Report::Plan { optimal: false },
+                Some("optimal") => Report::Plan { optimal: true },
+                _ => return Err("Expected single or optimal for --plan".to_owned()),
+            },
+            "--capacity" | "--required-free" | "--small-dir-max" => {
+                let value = parse_value(&arg, args.next())?;
+                match arg.as_str() {
+                    "--capacity" => options.disk.capacity = value,
+                    "--required-free" => options.disk.required_free = value,
+                    _ => options.disk.small_dir_max = value,
+                }
+                continue;
+            }
+            "--from-dir" | "--random" => {
+                let tree = match arg.as_str() {
+                    "--from-dir" => Tree::Dir(parse_value(&arg, args.next())?),
+                    _ => Tree::Random(parse_value(&arg, args.next())?),
+                };
+                if options.tree.replace(tree).is_some() {
+                    return Err("Only one of --from-dir and --random can be given".to_owned());
+                }
+                continue;
+            }
+            _ => {
+                rest.push(arg);
+                continue;
+            }
+        };
+
+        if options.report.replace(report).is_some() {
+            return Err("Only one report can be printed at a time".to_owned());
+        }
+    }
+
+    Ok((options, rest))
+}
+
+fn print_report(fs: &FileSystem, report: Report, disk: &DiskConfig) {
+    match report {
+        Report::Tree => print!("{}", tree(fs, fs.root())),
+        Report::Du => print!("{}", du(fs)),
+        Report::Top(n) => {
+            for dir in largest_dirs(fs, n) {
+                println!("{}\t{}", human_size(fs.size(dir)), fs.path(dir));
+            }
+        }
+        Report::Json => println!("{}", to_json(fs)),
+        Report::Transcript(order) => println!("{}", transcript(fs, order)),
+        Report::Plan { optimal } => {
+            let plan = match optimal {
+                true => plan_optimal(fs, disk),
+                false => plan_single(fs, disk),
+            };
+            match plan {
+                Some(plan) => {
+                    for dir in &plan.dirs {
+                        println!("{}\t{}", human_size(fs.size(*dir)), fs.path(*dir));
+                    }
+                    println!("freed {} of {} needed", plan.freed, disk.to_free(fs));
+                }
+                None => println!("Deleting everything doesn't free enough space"),
+            }
+        }
+    }
+}
+
+fn load(tree: Option<Tree>, rest: Vec<String>) -> Result<FileSystem, String> {
+    match tree {
+        Some(Tree::Dir(path)) => {
+            if !rest.is_empty() {
+                return Err("--from-dir doesn't take an input".to_owned());
+            }
+            from_dir(Path::new(&path)).map_err(|err| format!("Couldn't read {}: {}", path, err))
+        }
+        Some(Tree::Random(seed)) => match rest.is_empty() {
+            true => Ok(random_fs(&SyntheticTree {
+                seed,
+                ..SyntheticTree::default()
+            })),
+            false => Err("--random doesn't take an input".to_owned()),
+        },
+        None => {
+            let source = InputSource::from_args(rest)?;
+            let input = source.read(Path::new(env!("CARGO_MANIFEST_DIR")))?;
+            Day7::parse(&input).map_err(|err| err.to_string())
+        }
+    }
+}
 
 fn main() {
-    let contents = read_to_string(&Path::new("./data/input.txt")).unwrap();
-    let lines = contents.lines().collect::<Vec<&str>>();
+    let res = parse_options(env::args().skip(1))
+        .and_then(|(options, rest)| Ok((options.report, options.disk, load(options.tree, rest)?)));
+    let (report, disk, fs) = res.unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        eprintln!(
+            "Usage: [--tree | --du | --top <n> | --json | --transcript <depth|breadth|random[:<seed>]> \
+             | --plan <single|optimal>] [--capacity <size>] [--required-free <size>] \
+             [--small-dir-max <size>] [--from-dir <path> | --random <seed> | --demo | - | <path>]"
+        );
+        process::exit(1);
+    });
+
+    if let Some(report) = report {
+        print_report(&fs, report, &disk);
+        return;
+    }
 
-    let mut root = File::new_rc(File::new("/", true));
-    parse(root.clone(), 1, &lines);
-    update_dir_sizes(root.clone());
-    let part1_res = part1(root.clone());
-    println!("part 1: {:#?}", part1_res);
+    let part1_res = part1(&fs, &disk);
+    println!("part 1: {}", part1_res);
 
-    let part2_res = part2(root);
-    println!("part 2: {:#?}", part2_res);
+    match part2(&fs, &disk) {
+        Some(part2_res) => println!("part 2: {}", part2_res),
+        None => println!("part 2: no directory frees enough space"),
+    }
 }
@@ -0,0 +1,195 @@
+use serde::Serialize;
+use std::fmt::Write;
+
+use crate::fs::{FileSystem, NodeId};
+
+/// Draw `id` and everything below it like `tree` does, with the size of every node.
+pub fn tree(fs: &FileSystem, id: NodeId) -> String {
+    let mut res = String::new();
+    writeln!(res, "{} ({})", label(fs, id), fs.size(id)).unwrap();
+    tree_children(fs, id, "", &mut res);
+
+    res
+}
+
+fn tree_children(fs: &FileSystem, dir: NodeId, prefix: &str, res: &mut String) {
+    let children = fs.children(dir);
+    for (i, child) in children.iter().enumerate() {
+        let is_last = i + 1 == children.len();
+        let (branch, indent) = match is_last {
+            true => ("└── ", "    "),
+            false => ("├── ", "│   "),
+        };
+
+        writeln!(
+            res,
+            "{}{}{} ({})",
+            prefix,
+            branch,
+            label(fs, *child),
+            fs.size(*child)
+        )
+        .unwrap();
+        tree_children(fs, *child, &format!("{}{}", prefix, indent), res);
+    }
+}
+
+/// Directories end with a `/`, except for the root that already is one.
+fn label(fs: &FileSystem, id: NodeId) -> String {
+    match fs.is_dir(id) && id != fs.root() {
+        true => format!("{}/", fs.name(id)),
+        false => fs.name(id).to_owned(),
+    }
+}
+
+/// Format `size` the way `du -h` does: powers of 1024, rounded up, with one decimal below 10.
+pub fn human_size(size: usize) -> String {
+    const UNITS: [&str; 6] = ["K", "M", "G", "T", "P", "E"];
+
+    if size < 1024 {
+        return size.to_string();
+    }
+
+    let mut value = size as f64;
+    for unit in UNITS {
+        value /= 1024.0;
+        let tenths = (value * 10.0).ceil();
+        if tenths < 100.0 {
+            return format!("{:.1}{}", tenths / 10.0, unit);
+        }
+        if value.ceil() < 1024.0 {
+            return format!("{}{}", value.ceil(), unit);
+        }
+    }
+
+    unreachable!("{} is too large for a usize", size)
+}
+
+/// Every directory with its size, largest first, one per line like `du -h | sort -rh`.
+pub fn du(fs: &FileSystem) -> String {
+    largest_dirs(fs, fs.len())
+        .iter()
+        .map(|dir| format!("{}\t{}\n", human_size(fs.size(*dir)), fs.path(*dir)))
+        .collect()
+}
+
+/// The `n` largest directories, largest first. Directories of the same size keep their pre-order.
+pub fn largest_dirs(fs: &FileSystem, n: usize) -> Vec<NodeId> {
+    let mut dirs: Vec<_> = fs.dirs().collect();
+    dirs.sort_by_key(|dir| std::cmp::Reverse(fs.size(*dir)));
+    dirs.truncate(n);
+
+    dirs
+}
+
+/// A node of the hierarchy in the shape the JSON export writes it.
+#[derive(Debug, Serialize)]
+pub struct JsonNode {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: JsonKind,
+    pub size: usize,
+    /// Only written for directories
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub children: Option<Vec<JsonNode>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JsonKind {
+    Dir,
+    File,
+}
+
+impl JsonNode {
+    pub fn new(fs: &FileSystem, id: NodeId) -> Self {
+        let (kind, children) = match fs.is_dir(id) {
+            true => {
+                let children = fs.children(id).iter().map(|child| Self::new(fs, *child));
+                (JsonKind::Dir, Some(children.collect()))
+            }
+            false => (JsonKind::File, None),
+        };
+
+        Self {
+            name: fs.name(id).to_owned(),
+            kind,
+            size: fs.size(id),
+            children,
+        }
+    }
+}
+
+/// The whole hierarchy as pretty-printed JSON.
+pub fn to_json(fs: &FileSystem) -> String {
+    serde_json::to_string_pretty(&JsonNode::new(fs, fs.root())).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    const DEMO: &str = include_str!("../data/demo.txt");
+
+    #[test]
+    fn tree_works() {
+        let fs = parse(DEMO).unwrap();
+
+        assert_eq!(
+            tree(&fs, fs.lookup("/a").unwrap()),
+            "a/ (94853)\n\
+             ├── e/ (584)\n\
+             │   └── i (584)\n\
+             ├── f (29116)\n\
+             ├── g (2557)\n\
+             └── h.lst (62596)\n"
+        );
+        assert!(tree(&fs, fs.root()).starts_with("/ (48381165)\n├── a/ (94853)\n"));
+    }
+
+    #[test]
+    fn human_size_works() {
+        assert_eq!(human_size(584), "584");
+        assert_eq!(human_size(1024), "1.0K");
+        assert_eq!(human_size(1025), "1.1K");
+        assert_eq!(human_size(94853), "93K");
+        assert_eq!(human_size(24933642), "24M");
+        assert_eq!(human_size(1023 * 1024 + 1), "1.0M");
+        assert_eq!(human_size(48381165), "47M");
+    }
+
+    #[test]
+    fn du_works() {
+        let fs = parse(DEMO).unwrap();
+
+        assert_eq!(du(&fs), "47M\t/\n24M\t/d\n93K\t/a\n584\t/a/e\n");
+
+        let largest = largest_dirs(&fs, 2);
+        assert_eq!(largest, [fs.root(), fs.lookup("/d").unwrap()]);
+        assert_eq!(largest_dirs(&fs, 10).len(), 4);
+    }
+
+    #[test]
+    fn to_json_works() {
+        let mut fs = FileSystem::new();
+        let a = fs.add_dir(fs.root(), "a");
+        fs.add_file(a, "b", 10);
+
+        let json: serde_json::Value = serde_json::from_str(&to_json(&fs)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "name": "/",
+                "type": "dir",
+                "size": 10,
+                "children": [{
+                    "name": "a",
+                    "type": "dir",
+                    "size": 10,
+                    "children": [{ "name": "b", "type": "file", "size": 10 }],
+                }],
+            })
+        );
+    }
+}
@@ -0,0 +1,287 @@
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use std::{collections::VecDeque, fs, io, path::Path};
+
+use crate::fs::{FileSystem, NodeId};
+
+/// The order in which a transcript lists directories.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Order {
+    /// Like the puzzle's example: every directory before its subdirectories, going back up with
+    /// `cd ..`
+    DepthFirst,
+    /// Level by level, starting from the root
+    BreadthFirst,
+    /// Directories and their entries shuffled with the given seed
+    Random(u64),
+}
+
+/// Read the directory tree at `path` from disk.
+///
+/// Symlinks are skipped, as are entries whose name isn't UTF-8 or has a line break, since they
+/// can't be written in a transcript.
+pub fn from_dir(path: &Path) -> io::Result<FileSystem> {
+    let mut fs = FileSystem::new();
+    let root = fs.root();
+    add_dir_contents(&mut fs, root, path)?;
+
+    Ok(fs)
+}
+
+fn add_dir_contents(fs: &mut FileSystem, dir: NodeId, path: &Path) -> io::Result<()> {
+    let mut entries = fs::read_dir(path)?.collect::<io::Result<Vec<_>>>()?;
+    entries.sort_by_key(|entry| entry.file_name());
+
+    for entry in entries {
+        let name = match entry.file_name().into_string() {
+            Ok(name) if !name.contains(['\n', '\r']) => name,
+            _ => continue,
+        };
+        let file_type = entry.file_type()?;
+
+        if file_type.is_dir() {
+            let child = fs.add_dir(dir, &name);
+            add_dir_contents(fs, child, &entry.path())?;
+        } else if file_type.is_file() {
+            fs.add_file(dir, &name, entry.metadata()?.len() as usize);
+        }
+    }
+
+    Ok(())
+}
+
+/// The shape of a tree built by [`random_fs`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyntheticTree {
+    pub seed: u64,
+    /// How many directories deep the tree can go below the root
+    pub max_depth: usize,
+    pub max_entries: usize,
+    pub max_file_size: usize,
+}
+
+impl Default for SyntheticTree {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            max_depth: 4,
+            max_entries: 6,
+            max_file_size: 300_000,
+        }
+    }
+}
+
+/// A random tree of the given shape, always the same one for the same seed.
+pub fn random_fs(shape: &SyntheticTree) -> FileSystem {
+    let mut rng = StdRng::seed_from_u64(shape.seed);
+    let mut fs = FileSystem::new();
+    let root = fs.root();
+    add_random_entries(&mut fs, root, 0, shape, &mut rng);
+
+    fs
+}
+
+fn add_random_entries(
+    fs: &mut FileSystem,
+    dir: NodeId,
+    depth: usize,
+    shape: &SyntheticTree,
+    rng: &mut StdRng,
+) {
+    // an empty root would make for a pretty dull transcript
+    let min_entries = usize::from(depth == 0).min(shape.max_entries);
+    for _ in 0..rng.gen_range(min_entries..=shape.max_entries) {
+        let is_dir = depth < shape.max_depth && rng.gen_bool(0.3);
+        let name = random_name(rng, !is_dir);
+        if fs.child(dir, &name).is_some() {
+            continue;
+        }
+
+        match is_dir {
+            true => {
+                let child = fs.add_dir(dir, &name);
+                add_random_entries(fs, child, depth + 1, shape, rng);
+            }
+            false => {
+                fs.add_file(dir, &name, rng.gen_range(1..=shape.max_file_size));
+            }
+        }
+    }
+}
+
+fn random_name(rng: &mut StdRng, with_extension: bool) -> String {
+    let len = rng.gen_range(1..=8);
+    let name = random_letters(rng, len);
+
+    match with_extension && rng.gen_bool(0.5) {
+        true => format!("{}.{}", name, random_letters(rng, 3)),
+        false => name,
+    }
+}
+
+fn random_letters(rng: &mut StdRng, len: usize) -> String {
+    (0..len).map(|_| rng.gen_range('a'..='z')).collect()
+}
+
+/// Write the `cd` and `ls` commands that explore every directory of `fs` in `order`.
+///
+/// The transcript starts with `cd /` and parses back into the same tree.
+pub fn transcript(fs: &FileSystem, order: Order) -> String {
+    let mut rng = match order {
+        Order::Random(seed) => Some(StdRng::seed_from_u64(seed)),
+        _ => None,
+    };
+    let dirs = match order {
+        Order::DepthFirst => fs.dirs().collect(),
+        Order::BreadthFirst => breadth_first_dirs(fs),
+        Order::Random(_) => {
+            let mut dirs: Vec<_> = fs.dirs().collect();
+            dirs.shuffle(rng.as_mut().unwrap());
+            dirs
+        }
+    };
+
+    let mut lines = vec!["$ cd /".to_owned()];
+    let mut cwd = fs.root();
+    for dir in dirs {
+        move_to(fs, cwd, dir, order != Order::DepthFirst, &mut lines);
+        cwd = dir;
+
+        lines.push("$ ls".to_owned());
+        let mut children = fs.children(dir).to_vec();
+        if let Some(rng) = rng.as_mut() {
+            children.shuffle(rng);
+        }
+        for child in children {
+            lines.push(match fs.is_dir(child) {
+                true => format!("dir {}", fs.name(child)),
+                false => format!("{} {}", fs.size(child), fs.name(child)),
+            });
+        }
+    }
+
+    lines.join("\n")
+}
+
+fn breadth_first_dirs(fs: &FileSystem) -> Vec<NodeId> {
+    let mut res = vec![];
+    let mut queue = VecDeque::from([fs.root()]);
+
+    while let Some(dir) = queue.pop_front() {
+        res.push(dir);
+        queue.extend(fs.children(dir).iter().filter(|child| fs.is_dir(**child)));
+    }
+
+    res
+}
+
+/// The directories from the root down to `id`, both included.
+fn ancestry(fs: &FileSystem, id: NodeId) -> Vec<NodeId> {
+    let mut res = vec![id];
+    while let Some(parent) = fs.parent(*res.last().unwrap()) {
+        res.push(parent);
+    }
+    res.reverse();
+
+    res
+}
+
+/// Add the `cd` commands going from `from` to `to` through their closest common directory.
+///
+/// With `via_root`, going up to the root takes a single `cd /` instead of a `cd ..` per level.
+fn move_to(fs: &FileSystem, from: NodeId, to: NodeId, via_root: bool, lines: &mut Vec<String>) {
+    let from = ancestry(fs, from);
+    let to = ancestry(fs, to);
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+    let ups = from.len() - common;
+
+    if via_root && common == 1 && ups > 1 {
+        lines.push("$ cd /".to_owned());
+    } else {
+        lines.extend((0..ups).map(|_| "$ cd ..".to_owned()));
+    }
+    lines.extend(
+        to[common..]
+            .iter()
+            .map(|dir| format!("$ cd {}", fs.name(*dir))),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    const DEMO: &str = include_str!("../data/demo.txt");
+    const ORDERS: [Order; 4] = [
+        Order::DepthFirst,
+        Order::BreadthFirst,
+        Order::Random(1),
+        Order::Random(2),
+    ];
+
+    fn assert_round_trip(fs: &FileSystem, order: Order) {
+        let text = transcript(fs, order);
+        let parsed = parse(&text).unwrap();
+
+        assert_eq!(parsed.len(), fs.len(), "{:?}", order);
+        for id in fs.pre_order(fs.root()) {
+            let path = fs.path(id);
+            let parsed_id = parsed.lookup(&path).unwrap();
+            assert_eq!(parsed.size(parsed_id), fs.size(id), "{} {:?}", path, order);
+            assert_eq!(
+                parsed.is_dir(parsed_id),
+                fs.is_dir(id),
+                "{} {:?}",
+                path,
+                order
+            );
+        }
+    }
+
+    #[test]
+    fn transcript_works() {
+        let fs = parse(DEMO).unwrap();
+
+        assert_eq!(transcript(&fs, Order::DepthFirst), DEMO.trim_end());
+        for order in ORDERS {
+            assert_round_trip(&fs, order);
+        }
+    }
+
+    #[test]
+    fn random_fs_round_trips() {
+        for seed in 0..20 {
+            let fs = random_fs(&SyntheticTree {
+                seed,
+                ..SyntheticTree::default()
+            });
+            for order in ORDERS {
+                assert_round_trip(&fs, order);
+            }
+        }
+
+        let shape = SyntheticTree::default();
+        assert_eq!(random_fs(&shape), random_fs(&shape));
+    }
+
+    #[test]
+    fn from_dir_round_trips() {
+        let root = std::env::temp_dir().join(format!("q_7_from_dir_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("a").join("d")).unwrap();
+        fs::write(root.join("b.txt"), "abc").unwrap();
+        fs::write(root.join("a").join("c"), "hello").unwrap();
+
+        let res = from_dir(&root);
+        fs::remove_dir_all(&root).unwrap();
+        let fs = res.unwrap();
+
+        assert_eq!(
+            transcript(&fs, Order::DepthFirst),
+            "$ cd /\n$ ls\ndir a\n3 b.txt\n$ cd a\n$ ls\n5 c\ndir d\n$ cd d\n$ ls"
+        );
+        for order in ORDERS {
+            assert_round_trip(&fs, order);
+        }
+    }
+}
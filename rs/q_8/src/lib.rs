@@ -0,0 +1,125 @@
+use aoc_common::{Answer, Grid, ParseError, Result, Solution};
+
+pub type Trees = Grid<u8>;
+
+fn is_visible(i: usize, j: usize, trees: &Trees) -> bool {
+    let tree = trees[(i, j)];
+    let row = trees.row(i);
+
+    let left_visible = row[..j].iter().all(|current| *current < tree);
+    let right_visible = row[j + 1..].iter().all(|current| *current < tree);
+    let top_visible = trees.column(j).take(i).all(|current| *current < tree);
+    let bottom_visible = trees.column(j).skip(i + 1).all(|current| *current < tree);
+
+    left_visible || right_visible || top_visible || bottom_visible
+}
+
+/// How many trees can be seen from a tree of height `tree` looking along `line`.
+fn viewing_distance<'a>(tree: u8, line: impl Iterator<Item = &'a u8>) -> u32 {
+    let mut visible = 0;
+    for current in line {
+        visible += 1;
+        if *current >= tree {
+            break;
+        }
+    }
+
+    visible
+}
+
+fn scenic_view(i: usize, j: usize, trees: &Trees) -> u32 {
+    let tree = trees[(i, j)];
+    let row = trees.row(i);
+
+    let left_visible = viewing_distance(tree, row[..j].iter().rev());
+    let right_visible = viewing_distance(tree, row[j + 1..].iter());
+    let top_visible = viewing_distance(tree, trees.column(j).take(i).rev());
+    let bottom_visible = viewing_distance(tree, trees.column(j).skip(i + 1));
+
+    left_visible * right_visible * top_visible * bottom_visible
+}
+
+fn part1(trees: &Trees) -> u32 {
+    let mut visible = 0;
+
+    for (i, j) in trees.positions() {
+        let is_outer_row = i == 0 || i == trees.height() - 1;
+        let is_outer_col = j == 0 || j == trees.width() - 1;
+        if is_outer_row || is_outer_col || is_visible(i, j, trees) {
+            visible += 1;
+        }
+    }
+
+    visible
+}
+
+fn part2(trees: &Trees) -> u32 {
+    let mut max = 0;
+
+    for (i, j) in trees.positions() {
+        if i == 0 || i == trees.height() - 1 || j == 0 || j == trees.width() - 1 {
+            continue;
+        }
+
+        let current = scenic_view(i, j, trees);
+        if current > max {
+            max = current;
+        }
+    }
+
+    max
+}
+
+fn parse(input: &str) -> Result<Trees> {
+    Grid::from_chars(input, |_, ch| {
+        ch.to_digit(10)
+            .map(|height| height as u8)
+            .ok_or_else(|| ParseError::new("invalid tree height"))
+    })
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Trees;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(trees: &Self::Input) -> Answer {
+        part1(trees).into()
+    }
+
+    fn part2(trees: &Self::Input) -> Answer {
+        part2(trees).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::read_to_string;
+
+    use super::*;
+
+    #[test]
+    fn part1_works() {
+        let trees = parse(&read_to_string("./data/demo.txt").unwrap()).unwrap();
+        let result = part1(&trees);
+        assert_eq!(21, result);
+    }
+
+    #[test]
+    fn scenic_view_works() {
+        let trees = parse(&read_to_string("./data/demo.txt").unwrap()).unwrap();
+        let result = scenic_view(3, 2, &trees);
+        assert_eq!(8, result);
+    }
+
+    #[test]
+    fn part2_works() {
+        let trees = parse(&read_to_string("./data/demo.txt").unwrap()).unwrap();
+        let result = part2(&trees);
+        assert_eq!(8, result);
+    }
+}
@@ -0,0 +1,173 @@
+#[cfg(test)]
+mod tests;
+
+use std::collections::BTreeSet;
+
+use aoc_common::{parse_lines, parse_number, Answer, ParseError, Result, Solution};
+
+#[derive(Debug)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+pub struct Move {
+    direction: Direction,
+    amount: i32,
+}
+
+type Pos = (i32, i32);
+
+#[derive(Debug)]
+struct Rope {
+    pub positions: Vec<Pos>,
+    pub visited: BTreeSet<Pos>,
+}
+
+impl Rope {
+    pub fn handle_move(&mut self, mv: &Move) {
+        let pos_idx: Vec<usize> = (0..self.positions.len()).collect();
+
+        for _ in 0..mv.amount {
+            self.positions[0] = match mv.direction {
+                Direction::Up => (self.positions[0].0, self.positions[0].1 + 1),
+                Direction::Down => (self.positions[0].0, self.positions[0].1 - 1),
+                Direction::Left => (self.positions[0].0 - 1, self.positions[0].1),
+                Direction::Right => (self.positions[0].0 + 1, self.positions[0].1),
+            };
+
+            for win in pos_idx.windows(2) {
+                self.handle_segment_move(win[0], win[1]);
+            }
+
+            self.visited.insert(*self.positions.last().unwrap());
+        }
+    }
+
+    fn handle_segment_move(&mut self, head_idx: usize, tail_idx: usize) {
+        if are_neighbours(&self.positions[head_idx], &self.positions[tail_idx])
+            || self.positions[head_idx] == self.positions[tail_idx]
+        {
+            return;
+        }
+
+        if self.positions[head_idx].0 == self.positions[tail_idx].0 {
+            if self.positions[head_idx].1 > self.positions[tail_idx].1 {
+                self.positions[tail_idx] =
+                    (self.positions[tail_idx].0, self.positions[head_idx].1 - 1);
+            } else {
+                self.positions[tail_idx] =
+                    (self.positions[tail_idx].0, self.positions[head_idx].1 + 1);
+            }
+        } else if self.positions[head_idx].1 == self.positions[tail_idx].1 {
+            if self.positions[head_idx].0 > self.positions[tail_idx].0 {
+                self.positions[tail_idx] =
+                    (self.positions[head_idx].0 - 1, self.positions[tail_idx].1);
+            } else {
+                self.positions[tail_idx] =
+                    (self.positions[head_idx].0 + 1, self.positions[tail_idx].1);
+            }
+        } else if self.positions[head_idx].0 < self.positions[tail_idx].0
+            && self.positions[head_idx].1 > self.positions[tail_idx].1
+        {
+            self.positions[tail_idx] = (
+                self.positions[tail_idx].0 - 1,
+                self.positions[tail_idx].1 + 1,
+            );
+        } else if self.positions[head_idx].0 > self.positions[tail_idx].0
+            && self.positions[head_idx].1 > self.positions[tail_idx].1
+        {
+            self.positions[tail_idx] = (
+                self.positions[tail_idx].0 + 1,
+                self.positions[tail_idx].1 + 1,
+            );
+        } else if self.positions[head_idx].0 > self.positions[tail_idx].0
+            && self.positions[head_idx].1 < self.positions[tail_idx].1
+        {
+            self.positions[tail_idx] = (
+                self.positions[tail_idx].0 + 1,
+                self.positions[tail_idx].1 - 1,
+            );
+        } else {
+            self.positions[tail_idx] = (
+                self.positions[tail_idx].0 - 1,
+                self.positions[tail_idx].1 - 1,
+            );
+        }
+    }
+}
+
+impl Move {
+    pub fn new(input: &str) -> Result<Self> {
+        let (direction, amount) = input.split_once(' ').ok_or_else(|| {
+            ParseError::new("expected a direction and an amount").with_text(input)
+        })?;
+        let amount = parse_number(amount).map_err(|err| err.locate(input, amount))?;
+        let direction = match direction {
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            _ => return Err(ParseError::new("invalid direction").locate(input, direction)),
+        };
+
+        Ok(Self { direction, amount })
+    }
+}
+
+fn parse_moves(input: &str) -> Result<Vec<Move>> {
+    parse_lines(input, Move::new)
+}
+
+fn are_neighbours(pos1: &Pos, pos2: &Pos) -> bool {
+    pos1.0 - 1 <= pos2.0 && pos2.0 <= pos1.0 + 1 && pos1.1 - 1 <= pos2.1 && pos2.1 <= pos1.1 + 1
+}
+
+fn part1(moves: &[Move]) -> usize {
+    let mut rope = Rope {
+        positions: vec![(0, 0), (0, 0)],
+        visited: BTreeSet::new(),
+    };
+    rope.visited.insert((0, 0));
+
+    for mv in moves {
+        rope.handle_move(mv);
+    }
+
+    rope.visited.len()
+}
+
+fn part2(moves: &[Move]) -> usize {
+    let mut rope = Rope {
+        positions: [(0, 0)].repeat(10),
+        visited: BTreeSet::new(),
+    };
+    rope.visited.insert((0, 0));
+
+    for mv in moves {
+        rope.handle_move(mv);
+    }
+
+    rope.visited.len()
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Move>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_moves(input)
+    }
+
+    fn part1(moves: &Self::Input) -> Answer {
+        part1(moves).into()
+    }
+
+    fn part2(moves: &Self::Input) -> Answer {
+        part2(moves).into()
+    }
+}
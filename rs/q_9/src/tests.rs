@@ -1,22 +1,24 @@
+use std::fs::read_to_string;
+
 use super::*;
 
 #[test]
 fn part1_works() {
-    let moves = parse_moves("./data/demo.txt");
+    let moves = parse_moves(&read_to_string("./data/demo.txt").unwrap()).unwrap();
     let res = part1(&moves);
     assert_eq!(res, 13);
 }
 
 #[test]
 fn part2_works() {
-    let moves = parse_moves("./data/demo.txt");
+    let moves = parse_moves(&read_to_string("./data/demo.txt").unwrap()).unwrap();
     let res = part2(&moves);
     assert_eq!(res, 1);
 }
 
 #[test]
 fn part2_advanced_works() {
-    let moves = parse_moves("./data/demo2.txt");
+    let moves = parse_moves(&read_to_string("./data/demo2.txt").unwrap()).unwrap();
     let res = part2(&moves);
     assert_eq!(res, 36);
 }
\ No newline at end of file